@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface};
 
 use crate::errors::MomentumFiError;
-use crate::state::{UserAccount, Config};
+use crate::state::{UserAccount, Config, VestingAccount};
+use crate::utils::{available_emissions, record_emissions, vested_amount};
 
 #[derive(Accounts)]
 pub struct ClaimRewards<'info> {
@@ -14,9 +15,10 @@ pub struct ClaimRewards<'info> {
         init_if_needed,
         payer = user,
         associated_token::mint = rewards_mint,
-        associated_token::authority = user
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
     )]
-    pub user_rewards_ata: Account<'info, TokenAccount>, // User's ATA for the reward tokens
+    pub user_rewards_ata: InterfaceAccount<'info, TokenAccount>, // User's ATA for the reward tokens
     
     #[account(
         mut,
@@ -26,6 +28,7 @@ pub struct ClaimRewards<'info> {
     pub user_account: Account<'info, UserAccount>,
 
     #[account(
+        mut,
         seeds = [b"config"],
         bump = config.config_bump,
     )]
@@ -35,36 +38,131 @@ pub struct ClaimRewards<'info> {
         mut,
         seeds = [b"rewards", config.key().as_ref()],
         bump,
+        mint::token_program = token_program,
+    )]
+    pub rewards_mint: InterfaceAccount<'info, Mint>,
+
+    // Only used when vesting is enabled in the config
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = VestingAccount::INIT_SPACE + 8,
+        seeds = [b"vesting", user.key().as_ref()],
+        bump,
+    )]
+    pub vesting_account: Option<Account<'info, VestingAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = rewards_mint,
+        associated_token::authority = vesting_account,
+        associated_token::token_program = token_program,
     )]
-    pub rewards_mint: Account<'info, Mint>,
+    pub vesting_escrow: Option<InterfaceAccount<'info, TokenAccount>>, // Vesting account's ATA holding the locked rewards
 
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>, // Whichever token program owns the rewards mint
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> ClaimRewards<'info> {
-    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {    
+    pub fn claim_rewards(mut ctx: Context<ClaimRewards<'info>>) -> Result<()> {    
         // Extract claimable rewards first
         let rewards_points = ctx.accounts.user_account.claimable_rewards;
         require!(rewards_points > 0, MomentumFiError::NoPointsToClaim);
         
+        // Only claim the points that fit in the remaining emissions, the rest stays claimable for a later epoch
+        let conversion_rate = ctx.accounts.config.points_conversion_rate;
+        let available = available_emissions(&mut ctx.accounts.config, Clock::get()?.unix_timestamp);
+        let claimed_points = rewards_points.min(available / conversion_rate);
+        require!(claimed_points > 0, MomentumFiError::EmissionBudgetExhausted);
+
+        // Convert points to base units of the rewards mint
+        let amount = claimed_points
+            .checked_mul(conversion_rate)
+            .ok_or(MomentumFiError::ArithmeticOverflow)?;
+
         // Mint reward tokens and propagate error if it fails
-        Self::mint_reward_tokens(&ctx, rewards_points)?;
+        if ctx.accounts.config.vesting_enabled {
+            Self::mint_vested_reward_tokens(&mut ctx, amount)?;
+        } else {
+            let destination = ctx.accounts.user_rewards_ata.to_account_info();
+            Self::mint_reward_tokens(&ctx, destination, amount)?;
+        }
+        record_emissions(&mut ctx.accounts.config, amount)?;
+
+        if claimed_points < rewards_points {
+            msg!("Emission budget reached, {} points deferred", rewards_points - claimed_points);
+        }
 
         // Reset rewards after claiming
         let user_account = &mut ctx.accounts.user_account;
-        user_account.claimable_rewards = 0;
+        user_account.claimable_rewards -= claimed_points;
+
+        Ok(())
+    }
+
+    // Mints into the vesting escrow and adds the amount to the running schedule, or starts a new one
+    fn mint_vested_reward_tokens(ctx: &mut Context<ClaimRewards<'info>>, amount: u64) -> Result<()> {
+        let destination = ctx.accounts.vesting_escrow.as_ref().ok_or(MomentumFiError::VestingAccountsRequired)?.to_account_info();
+        Self::mint_reward_tokens(ctx, destination, amount)?;
+
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        let owner = ctx.accounts.user.key();
+        let (cliff, duration) = (ctx.accounts.config.vesting_cliff, ctx.accounts.config.vesting_duration);
+        let bump = ctx.bumps.vesting_account.ok_or(MomentumFiError::VestingAccountsRequired)?;
+        let vesting_account = ctx.accounts.vesting_account.as_mut().ok_or(MomentumFiError::VestingAccountsRequired)?;
+
+        let unlocked = vested_amount(
+            vesting_account.schedule_amount,
+            vesting_account.start_timestamp,
+            vesting_account.cliff,
+            vesting_account.duration,
+            current_timestamp,
+        );
+
+        if unlocked == vesting_account.schedule_amount {
+            // The schedule is done, its rest moves to the carry and the claim starts a new schedule
+            let carry_amount = vesting_account.carry_amount
+                .checked_add(unlocked.saturating_sub(vesting_account.schedule_withdrawn))
+                .ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+            vesting_account.set_inner(VestingAccount {
+                owner,
+                carry_amount,
+                schedule_amount: amount,
+                schedule_withdrawn: 0,
+                start_timestamp: current_timestamp,
+                cliff,
+                duration,
+                bump,
+            });
+        } else {
+            // Joins the running schedule with a start weighted by amount, so the tokens already
+            // locked keep their progress and only the new tokens start from now
+            let schedule_amount = vesting_account.schedule_amount
+                .checked_add(amount)
+                .ok_or(MomentumFiError::ArithmeticOverflow)?;
+            let start_timestamp = (vesting_account.start_timestamp as i128 * vesting_account.schedule_amount as i128
+                + current_timestamp as i128 * amount as i128)
+                / schedule_amount as i128;
+
+            vesting_account.schedule_amount = schedule_amount;
+            vesting_account.start_timestamp = start_timestamp as i64;
+        }
+
+        msg!("{} reward tokens added to vesting, {} in the schedule", amount, vesting_account.schedule_amount);
 
         Ok(())
     }
 
-    fn mint_reward_tokens(ctx: &Context<ClaimRewards>, amount: u64) -> Result<()> {
+    fn mint_reward_tokens(ctx: &Context<ClaimRewards<'info>>, destination: AccountInfo<'info>, amount: u64) -> Result<()> {
         let cpi_program = ctx.accounts.token_program.to_account_info();
 
         let cpi_accounts = MintTo {
             mint: ctx.accounts.rewards_mint.to_account_info(), 
-            to: ctx.accounts.user_rewards_ata.to_account_info(),
+            to: destination,
             authority: ctx.accounts.config.to_account_info(),
         };
 
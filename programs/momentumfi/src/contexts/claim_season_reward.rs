@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface};
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, Season, UserAccount};
+use crate::utils::{available_emissions, record_emissions};
+
+#[derive(Accounts)]
+#[instruction(season_number: u64)]
+pub struct ClaimSeasonReward<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = rewards_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_rewards_ata: InterfaceAccount<'info, TokenAccount>, // User's ATA for the reward tokens
+
+    #[account(
+        mut,
+        seeds = [b"user_account", user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"season", config.key().as_ref(), &season_number.to_le_bytes()],
+        bump = season.bump,
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"rewards", config.key().as_ref()],
+        bump = config.rewards_bump,
+        mint::token_program = token_program,
+    )]
+    pub rewards_mint: InterfaceAccount<'info, Mint>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimSeasonReward<'info> {
+    pub fn claim_season_reward(&mut self, season_number: u64) -> Result<()> {
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        require!(current_timestamp >= self.season.end_timestamp, MomentumFiError::SeasonNotEnded);
+
+        // The season points are either still the user's current counter or were moved to the last season slot
+        let user_account = &mut self.user_account;
+        let season_points = if user_account.season_number == season_number {
+            user_account.season_points
+        } else if user_account.last_season_number == season_number {
+            user_account.last_season_points
+        } else {
+            0
+        };
+        require!(season_points > 0, MomentumFiError::NoSeasonPoints);
+
+        // Share of the pool proportional to the user's season points
+        let amount = (self.season.reward_pool as u128)
+            .checked_mul(season_points as u128)
+            .and_then(|value| value.checked_div(self.season.total_points as u128))
+            .and_then(|value| u64::try_from(value).ok())
+            .ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+        let available = available_emissions(&mut self.config, current_timestamp);
+        require!(amount <= available, MomentumFiError::EmissionBudgetExhausted);
+
+        if user_account.season_number == season_number {
+            user_account.season_points = 0;
+        } else {
+            user_account.last_season_points = 0;
+        }
+
+        let cpi_accounts = MintTo {
+            mint: self.rewards_mint.to_account_info(),
+            to: self.user_rewards_ata.to_account_info(),
+            authority: self.config.to_account_info(),
+        };
+
+        let seeds = &[
+            &b"config"[..],
+            &[self.config.config_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_context = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+        mint_to(cpi_context, amount)?;
+
+        record_emissions(&mut self.config, amount)?;
+        self.season.distributed = self.season.distributed.checked_add(amount).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+        msg!("Claimed {} reward tokens for {} points in season {}", amount, season_points, season_number);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::MomentumFiError;
+use crate::events::SharedGoalContributed;
+use crate::state::SharedGoal;
+
+#[derive(Accounts)]
+pub struct ContributeSharedGoal<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    // The shared goal account holds the contributed SOL itself
+    #[account(
+        mut,
+        seeds = [b"shared_goal", shared_goal.creator.as_ref(), &shared_goal.goal_number.to_le_bytes()],
+        bump = shared_goal.bump,
+    )]
+    pub shared_goal: Account<'info, SharedGoal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ContributeSharedGoal<'info> {
+    pub fn contribute_shared_goal(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, MomentumFiError::InvalidVaultAmount);
+        // Late contributions would take a share of the bonus without counting towards the goal in time
+        require!(Clock::get()?.unix_timestamp <= self.shared_goal.deadline, MomentumFiError::SharedGoalExpired);
+        let index = self.shared_goal.member_index(&self.member.key()).ok_or(MomentumFiError::NotSharedGoalMember)?;
+
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.member.to_account_info(),
+                    to: self.shared_goal.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let shared_goal = &mut self.shared_goal;
+        shared_goal.contributions[index] = shared_goal.contributions[index].checked_add(amount).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+        emit!(SharedGoalContributed {
+            member: self.member.key(),
+            shared_goal: shared_goal.key(),
+            amount,
+            total_contributions: shared_goal.total_contributions(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{Challenge, Config, GoalKind};
+
+#[derive(Accounts)]
+pub struct CreateChallenge<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.config_bump,
+        constraint = config.authority == admin.key() @ MomentumFiError::UnauthorizedAccess,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Challenge::INIT_SPACE + 8,
+        seeds = [b"challenge", config.key().as_ref(), &config.challenge_count.to_le_bytes()],
+        bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateChallenge<'info> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_challenge(
+        &mut self,
+        kind: GoalKind,
+        target_usd: u64,
+        target_sol: u64,
+        entry_start: i64,
+        entry_end: i64,
+        deadline: i64,
+        reward_points: u64,
+        bumps: &CreateChallengeBumps,
+    ) -> Result<()> {
+        match kind {
+            GoalKind::Target => require!(target_usd > 0, MomentumFiError::InvalidChallenge),
+            GoalKind::TargetSol => require!(target_sol > 0, MomentumFiError::InvalidChallenge),
+            _ => return err!(MomentumFiError::InvalidChallenge),
+        }
+        require!(entry_end > entry_start && deadline > entry_end, MomentumFiError::InvalidChallenge);
+
+        let challenge_id = self.config.challenge_count;
+        self.challenge.set_inner(Challenge {
+            challenge_id,
+            kind,
+            target_usd,
+            target_sol,
+            entry_start,
+            entry_end,
+            deadline,
+            reward_points,
+            participants: 0,
+            bump: bumps.challenge,
+        });
+        self.config.challenge_count = challenge_id.checked_add(1).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+        msg!("Challenge {} created, entries open from {} to {}", challenge_id, entry_start, entry_end);
+
+        Ok(())
+    }
+}
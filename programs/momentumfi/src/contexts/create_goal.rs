@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
-use crate::state::{GoalAccount, UserAccount};
+use crate::errors::MomentumFiError;
+use crate::state::{GoalAccount, GoalKind, UserAccount, Config};
+use crate::utils::{update_sol_price, update_user_account, growth_target_usd, open_goal_slot};
 
 #[derive(Accounts)]
 #[instruction(goal_number: u64)]
@@ -24,15 +28,72 @@ pub struct CreateGoal<'info> {
     )]
     pub goal_account: Account<'info, GoalAccount>,
 
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Optional, escrow holding the SOL staked on the goal
+    #[account(
+        mut,
+        seeds = [b"goal_stake", goal_account.key().as_ref()],
+        bump,
+    )]
+    pub goal_stake: Option<SystemAccount<'info>>,
+
+    // Optional, refreshes the user's balances before the baseline is captured
+    pub price_update: Option<Account<'info, PriceUpdateV2>>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> CreateGoal<'info> {
-    pub fn create_goal(ctx: Context<CreateGoal>, goal_number: u64, target_usd: u64, deadline: i64) -> Result<()> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_goal(ctx: Context<CreateGoal>, goal_number: u64, target_usd: u64, deadline: i64, kind: GoalKind, growth_bps: u16, hold_days: u16, target_sol: u64, stake_lamports: u64) -> Result<()> {
         let clock = Clock::get()?;
         let bumps = &ctx.bumps;
-        ctx.accounts.goal_account.set_inner(GoalAccount { 
-            user: ctx.accounts.user.key(), 
+
+        // Refresh the balances when a price update is provided, otherwise the cached balances are used as baseline.
+        // Growth goals and difficulty scaling need a fresh baseline, a stale one would inflate the gap.
+        match &ctx.accounts.price_update {
+            Some(price_update) => {
+                update_sol_price(&mut ctx.accounts.config, price_update)?;
+                update_user_account(&mut ctx.accounts.user_account, &ctx.accounts.user, &ctx.accounts.config)?;
+            }
+            None => require!(
+                kind != GoalKind::Growth && ctx.accounts.config.max_difficulty_bps == 0,
+                MomentumFiError::PriceUpdateRequired
+            ),
+        }
+
+        let baseline_usd = ctx.accounts.user_account.usd_balance;
+        let baseline_sol = ctx.accounts.user_account.sol_balance;
+
+        // Growth goals derive their target from the baseline
+        let target_usd = match kind {
+            GoalKind::Target => target_usd,
+            GoalKind::HoldDuration => {
+                require!(hold_days > 0, MomentumFiError::InvalidHoldDuration);
+                target_usd
+            }
+            GoalKind::StayAbove => {
+                require!(deadline > clock.unix_timestamp, MomentumFiError::InvalidDeadline);
+                target_usd
+            }
+            GoalKind::TargetSol => {
+                require!(target_sol > 0, MomentumFiError::InvalidSolTarget);
+                target_usd
+            }
+            GoalKind::Growth => {
+                require!(growth_bps > 0, MomentumFiError::InvalidGrowthTarget);
+                growth_target_usd(baseline_usd, growth_bps)?
+            }
+        };
+
+        ctx.accounts.goal_account.set_inner(GoalAccount {
+            user: ctx.accounts.user.key(),
             total_points: 0, // ✅ Explicit
             creation_timestamp: clock.unix_timestamp.to_le(), // ✅ Explicit
             target_usd: target_usd.to_le(),
@@ -41,16 +102,59 @@ impl<'info> CreateGoal<'info> {
             goal_number: goal_number.to_le(),
             completed: false, // ✅ Explicit
             first_completed_bonus: false, // ✅ Explicit
+            kind,
+            growth_bps: growth_bps.to_le(),
+            baseline_usd,
+            baseline_sol,
+            breached: false,
+            breach_timestamp: 0,
+            breach_usd_balance: 0,
+            hold_days: hold_days.to_le(),
+            streak_start_timestamp: 0,
+            streak_days: 0,
+            daily_streak: 0,
+            target_sol,
+            challenge: Pubkey::default(),
+            challenge_reward_points: 0,
+            stake_lamports,
+            completed_timestamp: 0,
+            vault_lamports: 0,
+            guardian: Pubkey::default(),
+            quest: Pubkey::default(),
+            quest_step: 0,
+            first_completed_points_override: 0,
+            daily_points_override: 0,
+            last_hold_observation: 0,
             bump: bumps.goal_account,
-            _padding: [0; 5], // ✅ Explicit // Set padding to an array of 5 zero bytes
+            _padding: [0; 2], // ✅ Explicit // Set padding to an array of 2 zero bytes
         });
 
+        // Lock the stake, it is returned when the goal completes before the deadline and forfeited otherwise
+        if stake_lamports > 0 {
+            let goal_stake = ctx.accounts.goal_stake.as_ref().ok_or(MomentumFiError::GoalStakeRequired)?;
+            require!(stake_lamports >= Rent::get()?.minimum_balance(0), MomentumFiError::GoalStakeTooSmall);
+            require!(deadline > clock.unix_timestamp, MomentumFiError::InvalidDeadline);
+
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.user.to_account_info(),
+                        to: goal_stake.to_account_info(),
+                    },
+                ),
+                stake_lamports,
+            )?;
+            msg!("🔒 Staked {} lamports on the goal", stake_lamports);
+        }
+
         msg!("📌 Storing GoalAccount with creation_timestamp: {}", clock.unix_timestamp);
         msg!("GoalAccount after creation: {:?}", ctx.accounts.goal_account);
 
         // Increment goal count for the user
+        open_goal_slot(&mut ctx.accounts.user_account, &ctx.accounts.config)?;
         ctx.accounts.user_account.goal_count += 1;
 
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, GoalAccount, GoalKind, GoalTemplate, UserAccount};
+use crate::utils::{growth_target_usd, open_goal_slot, update_sol_price, update_user_account};
+
+#[derive(Accounts)]
+#[instruction(goal_number: u64)]
+pub struct CreateGoalFromTemplate<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_account", user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        seeds = [b"goal_template", config.key().as_ref(), &goal_template.template_id.to_le_bytes()],
+        bump = goal_template.bump,
+    )]
+    pub goal_template: Account<'info, GoalTemplate>,
+
+    #[account(
+        init,
+        payer = user,
+        space = GoalAccount::INIT_SPACE,
+        seeds = [b"goal_account", user_account.key().as_ref(), &goal_number.to_le_bytes()],
+        bump,
+    )]
+    pub goal_account: Account<'info, GoalAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Optional, refreshes the user's balances before the baseline is captured
+    pub price_update: Option<Account<'info, PriceUpdateV2>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateGoalFromTemplate<'info> {
+    pub fn create_goal_from_template(&mut self, goal_number: u64, bumps: &CreateGoalFromTemplateBumps) -> Result<()> {
+        let clock = Clock::get()?;
+        let template = &self.goal_template;
+
+        // Same baseline rules as create_goal
+        match &self.price_update {
+            Some(price_update) => {
+                update_sol_price(&mut self.config, price_update)?;
+                update_user_account(&mut self.user_account, &self.user, &self.config)?;
+            }
+            None => require!(
+                template.kind != GoalKind::Growth && self.config.max_difficulty_bps == 0,
+                MomentumFiError::PriceUpdateRequired
+            ),
+        }
+
+        let baseline_usd = self.user_account.usd_balance;
+        let target_usd = match template.kind {
+            GoalKind::Growth => growth_target_usd(baseline_usd, template.growth_bps)?,
+            _ => template.target_usd,
+        };
+
+        self.goal_account.set_inner(GoalAccount {
+            user: self.user.key(),
+            creation_timestamp: clock.unix_timestamp,
+            target_usd,
+            deadline: clock.unix_timestamp.saturating_add(template.duration),
+            goal_number,
+            kind: template.kind,
+            growth_bps: template.growth_bps,
+            baseline_usd,
+            baseline_sol: self.user_account.sol_balance,
+            hold_days: template.hold_days,
+            target_sol: template.target_sol,
+            first_completed_points_override: template.first_completed_points_override,
+            daily_points_override: template.daily_points_override,
+            bump: bumps.goal_account,
+            ..GoalAccount::default()
+        });
+
+        open_goal_slot(&mut self.user_account, &self.config)?;
+        self.user_account.goal_count += 1;
+
+        msg!("Goal {} created from template {}", goal_number, template.name);
+
+        Ok(())
+    }
+}
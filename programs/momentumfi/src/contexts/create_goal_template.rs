@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, GoalKind, GoalTemplate, MAX_TEMPLATE_NAME_LENGTH};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GoalTemplateParams {
+    pub name: String,
+    pub kind: GoalKind,
+    pub target_usd: u64,
+    pub target_sol: u64,
+    pub growth_bps: u16,
+    pub hold_days: u16,
+    pub duration: i64,
+    pub first_completed_points_override: u64,
+    pub daily_points_override: u64,
+}
+
+#[derive(Accounts)]
+pub struct CreateGoalTemplate<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.config_bump,
+        constraint = config.authority == admin.key() @ MomentumFiError::UnauthorizedAccess,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = GoalTemplate::INIT_SPACE + 8,
+        seeds = [b"goal_template", config.key().as_ref(), &config.goal_template_count.to_le_bytes()],
+        bump,
+    )]
+    pub goal_template: Account<'info, GoalTemplate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateGoalTemplate<'info> {
+    pub fn create_goal_template(&mut self, template: GoalTemplateParams, bumps: &CreateGoalTemplateBumps) -> Result<()> {
+        require!(template.name.len() <= MAX_TEMPLATE_NAME_LENGTH, MomentumFiError::NameTooLong);
+        require!(template.duration > 0, MomentumFiError::InvalidGoalTemplate);
+        let valid = match template.kind {
+            GoalKind::Target | GoalKind::StayAbove => true,
+            GoalKind::TargetSol => template.target_sol > 0,
+            GoalKind::Growth => template.growth_bps > 0,
+            GoalKind::HoldDuration => template.hold_days > 0,
+        };
+        require!(valid, MomentumFiError::InvalidGoalTemplate);
+
+        let template_id = self.config.goal_template_count;
+        self.goal_template.set_inner(GoalTemplate {
+            template_id,
+            name: template.name,
+            kind: template.kind,
+            target_usd: template.target_usd,
+            target_sol: template.target_sol,
+            growth_bps: template.growth_bps,
+            hold_days: template.hold_days,
+            duration: template.duration,
+            first_completed_points_override: template.first_completed_points_override,
+            daily_points_override: template.daily_points_override,
+            bump: bumps.goal_template,
+        });
+        self.config.goal_template_count = template_id.checked_add(1).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+        msg!("Goal template {} created: {}", template_id, self.goal_template.name);
+
+        Ok(())
+    }
+}
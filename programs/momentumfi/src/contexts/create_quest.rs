@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, GoalKind, QuestStep, QuestTemplate, MAX_QUEST_STEPS};
+
+#[derive(Accounts)]
+pub struct CreateQuest<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.config_bump,
+        constraint = config.authority == admin.key() @ MomentumFiError::UnauthorizedAccess,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = QuestTemplate::INIT_SPACE + 8,
+        seeds = [b"quest", config.key().as_ref(), &config.quest_count.to_le_bytes()],
+        bump,
+    )]
+    pub quest_template: Account<'info, QuestTemplate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateQuest<'info> {
+    pub fn create_quest(&mut self, steps: Vec<QuestStep>, step_duration: i64, chain_bonus_points: u64, bumps: &CreateQuestBumps) -> Result<()> {
+        require!(!steps.is_empty() && steps.len() <= MAX_QUEST_STEPS, MomentumFiError::InvalidQuest);
+        require!(step_duration > 0, MomentumFiError::InvalidQuest);
+
+        let mut quest_steps = [QuestStep::default(); MAX_QUEST_STEPS];
+        for (i, step) in steps.iter().enumerate() {
+            let valid = match step.kind {
+                GoalKind::Target => true,
+                GoalKind::TargetSol => step.target_sol > 0,
+                GoalKind::HoldDuration => step.hold_days > 0,
+                _ => false,
+            };
+            require!(valid, MomentumFiError::InvalidQuest);
+            quest_steps[i] = *step;
+        }
+
+        let quest_id = self.config.quest_count;
+        self.quest_template.set_inner(QuestTemplate {
+            quest_id,
+            steps: quest_steps,
+            step_count: steps.len() as u8,
+            step_duration,
+            chain_bonus_points,
+            bump: bumps.quest_template,
+        });
+        self.config.quest_count = quest_id.checked_add(1).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+        msg!("Quest {} created with {} steps", quest_id, steps.len());
+
+        Ok(())
+    }
+}
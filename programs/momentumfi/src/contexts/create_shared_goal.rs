@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{SharedGoal, MAX_SHARED_GOAL_MEMBERS};
+
+#[derive(Accounts)]
+#[instruction(goal_number: u64)]
+pub struct CreateSharedGoal<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = SharedGoal::INIT_SPACE + 8,
+        seeds = [b"shared_goal", creator.key().as_ref(), &goal_number.to_le_bytes()],
+        bump,
+    )]
+    pub shared_goal: Account<'info, SharedGoal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateSharedGoal<'info> {
+    pub fn create_shared_goal(&mut self, goal_number: u64, target_usd: u64, deadline: i64, members: Vec<Pubkey>, bumps: &CreateSharedGoalBumps) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(deadline > clock.unix_timestamp, MomentumFiError::InvalidDeadline);
+        require!(target_usd > 0, MomentumFiError::InvalidUsdTarget);
+        require!(members.len() < MAX_SHARED_GOAL_MEMBERS, MomentumFiError::InvalidSharedGoalMembers);
+
+        // The creator is always the first member
+        let mut all_members = [Pubkey::default(); MAX_SHARED_GOAL_MEMBERS];
+        all_members[0] = self.creator.key();
+        for (i, member) in members.iter().enumerate() {
+            require!(
+                *member != Pubkey::default() && !all_members[..=i].contains(member),
+                MomentumFiError::InvalidSharedGoalMembers
+            );
+            all_members[i + 1] = *member;
+        }
+
+        self.shared_goal.set_inner(SharedGoal {
+            creator: self.creator.key(),
+            goal_number,
+            members: all_members,
+            member_count: (members.len() + 1) as u8,
+            contributions: [0; MAX_SHARED_GOAL_MEMBERS],
+            target_usd,
+            deadline,
+            creation_timestamp: clock.unix_timestamp,
+            total_points: 0,
+            completed: false,
+            first_completed_bonus: false,
+            bump: bumps.shared_goal,
+        });
+
+        msg!("Shared goal {} created with {} members", goal_number, members.len() + 1);
+
+        Ok(())
+    }
+}
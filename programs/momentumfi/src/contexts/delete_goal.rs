@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::errors::MomentumFiError;
 use crate::state::{GoalAccount, UserAccount};
+use crate::utils::close_goal_slot;
 
 #[derive(Accounts)]
 pub struct DeleteGoal<'info> {
@@ -9,6 +10,7 @@ pub struct DeleteGoal<'info> {
     pub user: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [b"user_account", user.key().as_ref()],
         bump = user_account.bump,
     )]
@@ -18,6 +20,9 @@ pub struct DeleteGoal<'info> {
         mut,
         close = user, // Refund rent to the user when closing the account
         has_one = user @ MomentumFiError::UnauthorizedDelete, // Ensure the user is the owner
+        constraint = goal_account.stake_lamports == 0 @ MomentumFiError::GoalStakeLocked, // Reclaim or forfeit the stake first
+        constraint = goal_account.vault_lamports == 0 @ MomentumFiError::VaultNotEmpty, // Withdraw the savings first
+        constraint = goal_account.guardian == Pubkey::default() @ MomentumFiError::UnauthorizedDelete, // Guardian-managed goals are deleted by the guardian
         seeds = [b"goal_account", user_account.key().as_ref(), &goal_account.goal_number.to_le_bytes()],
         bump = goal_account.bump,
     )]
@@ -27,8 +32,9 @@ pub struct DeleteGoal<'info> {
 }
 
 impl<'info> DeleteGoal<'info> {
-    pub fn delete_goal() -> Result<()> {
+    pub fn delete_goal(ctx: Context<DeleteGoal>) -> Result<()> {
         // The goal_account is automatically closed by the `close = user` constraint.
+        close_goal_slot(&mut ctx.accounts.user_account);
         msg!("Goal successfully deleted.");
         Ok(())
     }
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::MomentumFiError;
+use crate::events::VaultDeposited;
+use crate::state::{GoalAccount, UserAccount};
+
+#[derive(Accounts)]
+pub struct DepositToVault<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"user_account", user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        has_one = user @ MomentumFiError::UnauthorizedAccess,
+        seeds = [b"goal_account", user_account.key().as_ref(), &goal_account.goal_number.to_le_bytes()],
+        bump = goal_account.bump,
+    )]
+    pub goal_account: Account<'info, GoalAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"goal_vault", goal_account.key().as_ref()],
+        bump,
+    )]
+    pub goal_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositToVault<'info> {
+    pub fn deposit_to_vault(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, MomentumFiError::InvalidVaultAmount);
+        // An empty vault has to be funded above the rent-exempt minimum
+        require!(
+            self.goal_vault.lamports() > 0 || amount >= Rent::get()?.minimum_balance(0),
+            MomentumFiError::InvalidVaultAmount
+        );
+
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.user.to_account_info(),
+                    to: self.goal_vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let goal_account = &mut self.goal_account;
+        goal_account.vault_lamports = goal_account.vault_lamports.checked_add(amount).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+        emit!(VaultDeposited {
+            user: self.user.key(),
+            goal_account: goal_account.key(),
+            amount,
+            vault_lamports: goal_account.vault_lamports,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, GoalAccount, UserAccount};
+use crate::utils::{release_goal_stake, stake_forfeitable};
+
+// Permissionless, anyone can settle a failed goal once its deadline passed
+#[derive(Accounts)]
+pub struct ForfeitGoalStake<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [b"user_account", goal_account.user.as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"goal_account", user_account.key().as_ref(), &goal_account.goal_number.to_le_bytes()],
+        bump = goal_account.bump,
+    )]
+    pub goal_account: Account<'info, GoalAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"goal_stake", goal_account.key().as_ref()],
+        bump,
+    )]
+    pub goal_stake: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = config.treasury,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ForfeitGoalStake<'info> {
+    pub fn forfeit_goal_stake(&mut self, bumps: &ForfeitGoalStakeBumps) -> Result<()> {
+        require!(self.goal_account.stake_lamports > 0, MomentumFiError::NoGoalStake);
+        require!(stake_forfeitable(&self.goal_account, Clock::get()?.unix_timestamp), MomentumFiError::GoalStakeNotForfeitable);
+
+        let amount = self.goal_stake.lamports();
+        release_goal_stake(&self.goal_stake, self.treasury.to_account_info(), &self.goal_account, bumps.goal_stake, &self.system_program)?;
+        self.goal_account.stake_lamports = 0;
+
+        msg!("Goal stake of {} lamports forfeited to the treasury", amount);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, UserAccount};
+
+#[derive(Accounts)]
+pub struct GrantStreakFreezes<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_account", user_account.owner.as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.config_bump,
+        constraint = config.authority == admin.key() @ MomentumFiError::UnauthorizedAccess,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> GrantStreakFreezes<'info> {
+    pub fn grant_streak_freezes(&mut self, amount: u16) -> Result<()> {
+        self.user_account.streak_freezes = self.user_account.streak_freezes
+            .checked_add(amount)
+            .ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+        msg!("Granted {} streak freezes, balance is now {}", amount, self.user_account.streak_freezes);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, GoalAccount, GoalKind, GuardianLink, UserAccount};
+use crate::utils::{close_goal_slot, open_goal_slot};
+
+#[derive(Accounts)]
+#[instruction(goal_number: u64)]
+pub struct GuardianCreateGoal<'info> {
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_account", dependent_account.owner.as_ref()],
+        bump = dependent_account.bump,
+    )]
+    pub dependent_account: Account<'info, UserAccount>,
+
+    #[account(
+        has_one = guardian @ MomentumFiError::UnauthorizedAccess,
+        seeds = [b"guardian_link", dependent_account.key().as_ref()],
+        bump = guardian_link.bump,
+    )]
+    pub guardian_link: Account<'info, GuardianLink>,
+
+    #[account(
+        init,
+        payer = guardian,
+        space = GoalAccount::INIT_SPACE,
+        seeds = [b"goal_account", dependent_account.key().as_ref(), &goal_number.to_le_bytes()],
+        bump,
+    )]
+    pub goal_account: Account<'info, GoalAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GuardianManageGoal<'info> {
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_account", dependent_account.owner.as_ref()],
+        bump = dependent_account.bump,
+    )]
+    pub dependent_account: Account<'info, UserAccount>,
+
+    #[account(
+        has_one = guardian @ MomentumFiError::UnauthorizedAccess,
+        seeds = [b"guardian_link", dependent_account.key().as_ref()],
+        bump = guardian_link.bump,
+    )]
+    pub guardian_link: Account<'info, GuardianLink>,
+
+    // Only goals the guardian created can be managed by the guardian
+    #[account(
+        mut,
+        constraint = goal_account.guardian == guardian.key() @ MomentumFiError::UnauthorizedAccess,
+        seeds = [b"goal_account", dependent_account.key().as_ref(), &goal_account.goal_number.to_le_bytes()],
+        bump = goal_account.bump,
+    )]
+    pub goal_account: Account<'info, GoalAccount>,
+}
+
+impl<'info> GuardianCreateGoal<'info> {
+    pub fn guardian_create_goal(&mut self, goal_number: u64, kind: GoalKind, target_usd: u64, target_sol: u64, deadline: i64, bumps: &GuardianCreateGoalBumps) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(deadline > clock.unix_timestamp, MomentumFiError::InvalidDeadline);
+        match kind {
+            GoalKind::Target => {}
+            GoalKind::TargetSol => require!(target_sol > 0, MomentumFiError::InvalidSolTarget),
+            _ => return err!(MomentumFiError::InvalidGoalKind),
+        }
+
+        self.goal_account.set_inner(GoalAccount {
+            user: self.dependent_account.owner,
+            creation_timestamp: clock.unix_timestamp,
+            target_usd,
+            deadline,
+            goal_number,
+            kind,
+            baseline_usd: self.dependent_account.usd_balance,
+            baseline_sol: self.dependent_account.sol_balance,
+            target_sol,
+            guardian: self.guardian.key(),
+            bump: bumps.goal_account,
+            ..GoalAccount::default()
+        });
+
+        open_goal_slot(&mut self.dependent_account, &self.config)?;
+        self.dependent_account.goal_count += 1;
+
+        msg!("Guardian created goal {} for {}", goal_number, self.dependent_account.owner);
+
+        Ok(())
+    }
+}
+
+impl<'info> GuardianManageGoal<'info> {
+    pub fn guardian_update_goal(&mut self, target_usd: u64, target_sol: u64, deadline: i64) -> Result<()> {
+        let goal_account = &mut self.goal_account;
+        require!(!goal_account.first_completed_bonus, MomentumFiError::GoalAlreadyCompleted);
+        require!(deadline > Clock::get()?.unix_timestamp, MomentumFiError::InvalidDeadline);
+        // Savings and stakes unlock on completion, so their targets cannot be moved
+        require!(goal_account.stake_lamports == 0, MomentumFiError::GoalStakeLocked);
+        require!(goal_account.vault_lamports == 0, MomentumFiError::VaultNotEmpty);
+        if goal_account.kind == GoalKind::TargetSol {
+            require!(target_sol > 0, MomentumFiError::InvalidSolTarget);
+        }
+
+        goal_account.target_usd = target_usd;
+        goal_account.target_sol = target_sol;
+        goal_account.deadline = deadline;
+
+        msg!("Guardian updated goal {}", goal_account.goal_number);
+
+        Ok(())
+    }
+
+    pub fn guardian_delete_goal(&mut self) -> Result<()> {
+        require!(self.goal_account.stake_lamports == 0, MomentumFiError::GoalStakeLocked);
+        require!(self.goal_account.vault_lamports == 0, MomentumFiError::VaultNotEmpty);
+
+        // Rent goes back to the guardian who paid for the goal
+        self.goal_account.close(self.guardian.to_account_info())?;
+        close_goal_slot(&mut self.dependent_account);
+
+        msg!("Guardian deleted goal {}", self.goal_account.goal_number);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, Leaderboard, LeaderboardEntry, LEADERBOARD_SIZE};
+
+#[derive(Accounts)]
+pub struct InitLeaderboard<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.config_bump,
+        constraint = config.authority == admin.key() @ MomentumFiError::UnauthorizedAccess,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Leaderboard::INIT_SPACE + 8,
+        seeds = [b"leaderboard", config.key().as_ref()],
+        bump,
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitLeaderboard<'info> {
+    pub fn init_leaderboard(&mut self, bumps: &InitLeaderboardBumps) -> Result<()> {
+        self.leaderboard.set_inner(Leaderboard {
+            entries: [LeaderboardEntry::default(); LEADERBOARD_SIZE],
+            bump: bumps.leaderboard,
+        });
+
+        msg!("Leaderboard initialized with {} slots", LEADERBOARD_SIZE);
+
+        Ok(())
+    }
+}
@@ -1,8 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token};
+use anchor_lang::system_program::{allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::metadata::mpl_token_metadata;
+use anchor_spl::token_2022::spl_token_2022::{self, extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions}};
+use anchor_spl::token_interface::{
+    initialize_mint2, metadata_pointer_initialize, non_transferable_mint_initialize,
+    InitializeMint2, MetadataPointerInitialize, NonTransferableMintInitialize, TokenInterface,
+};
 
 use crate::errors::MomentumFiError;
-use crate::state::Config;
+use crate::state::{Config, StreakMultiplier, STREAK_TIERS};
+
+pub const REWARDS_DECIMALS: u8 = 6;
+pub const DEFAULT_POINTS_CONVERSION_RATE: u64 = 10u64.pow(REWARDS_DECIMALS as u32); // 1 point = 1 token
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -18,17 +27,15 @@ pub struct Initialize<'info> {
     )]
     pub config_account: Account<'info, Config>,
 
+    /// CHECK: The rewards mint is created in the handler so its extensions can be selected at initialization
     #[account(
-        init_if_needed,
-        payer = admin,
+        mut,
         seeds = [b"rewards", config_account.key().as_ref()],
-        mint::decimals = 6,
-        mint::authority = config_account,
         bump,
     )]
-    pub rewards_mint: Account<'info, Mint>,
+    pub rewards_mint: UncheckedAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -37,6 +44,8 @@ impl<'info> Initialize<'info> {
         &mut self,
         first_completed_points: u16,
         daily_points: u16,
+        soulbound: bool,
+        metadata_pointer: bool,
         bumps: &InitializeBumps,
     ) -> Result<()> {
         msg!("🚀 Initialize instruction called!");
@@ -47,6 +56,39 @@ impl<'info> Initialize<'info> {
 
         require!(first_completed_points > 0 && first_completed_points < 10000, MomentumFiError::TooBigPointsValue);
 
+        // Only the current authority can re-initialize an existing config
+        let reinitializing = self.config_account.authority != Pubkey::default();
+        require!(
+            !reinitializing || self.config_account.authority == self.admin.key(),
+            MomentumFiError::UnauthorizedAccess
+        );
+
+        // Mint extensions are only available with Token-2022
+        require!(
+            !(soulbound || metadata_pointer) || self.token_program.key() == spl_token_2022::ID,
+            MomentumFiError::InvalidTokenProgram
+        );
+
+        // Re-initializing keeps the existing mint, so its extensions can't be changed
+        if self.rewards_mint.data_is_empty() {
+            self.create_rewards_mint(soulbound, metadata_pointer, bumps)?;
+        } else {
+            require!(
+                self.rewards_mint_extensions()? == (soulbound, metadata_pointer),
+                MomentumFiError::RewardsMintMismatch
+            );
+        }
+
+        // Re-initializing only updates the points, every other setting keeps the value given through its setter
+        if reinitializing {
+            self.config_account.first_completed_points = first_completed_points;
+            self.config_account.daily_points = daily_points;
+
+            msg!("✅ Config account successfully updated!");
+
+            return Ok(());
+        }
+
         self.config_account.set_inner(Config {
             authority: self.admin.key(),
             first_completed_points,
@@ -55,10 +97,172 @@ impl<'info> Initialize<'info> {
             price_last_updated: 0,
             config_bump: bumps.config_account,
             rewards_bump: bumps.rewards_mint,
+            streak_multipliers: [StreakMultiplier::default(); STREAK_TIERS],
+            rewards_token_program: *self.rewards_mint.owner,
+            soulbound,
+            points_conversion_rate: DEFAULT_POINTS_CONVERSION_RATE,
+            max_supply: 0,
+            total_minted: 0,
+            emissions_start: 0,
+            epoch_duration: 0,
+            epoch_emission_budget: 0,
+            halving_interval: 0,
+            current_epoch: 0,
+            epoch_minted: 0,
+            vesting_enabled: false,
+            vesting_cliff: 0,
+            vesting_duration: 0,
+            stake_boost_unit: 0,
+            stake_boost_bps_per_unit: 0,
+            lock_boost_bps_per_day: 0,
+            max_stake_boost_bps: 0,
+            referral_bonus_points: 0,
+            current_season: 0,
+            challenge_count: 0,
+            treasury: self.admin.key(),
+            quest_count: 0,
+            goal_template_count: 0,
+            difficulty_gap_weight_bps: 0,
+            difficulty_reference_days: 0,
+            min_difficulty_bps: 0,
+            max_difficulty_bps: 0,
+            goal_slots: 0,
+            achievement_base_uri: String::new(),
         });
 
         msg!("✅ Config account successfully set!");
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    // Whether the existing rewards mint is non-transferable and has a metadata pointer
+    fn rewards_mint_extensions(&self) -> Result<(bool, bool)> {
+        if *self.rewards_mint.owner != spl_token_2022::ID {
+            return Ok((false, false));
+        }
+
+        let data = self.rewards_mint.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        let extensions = mint.get_extension_types()?;
+
+        Ok((
+            extensions.contains(&ExtensionType::NonTransferable),
+            extensions.contains(&ExtensionType::MetadataPointer),
+        ))
+    }
+
+    // Creates the rewards mint owned by the given token program, with the selected Token-2022 extensions
+    fn create_rewards_mint(&self, soulbound: bool, metadata_pointer: bool, bumps: &InitializeBumps) -> Result<()> {
+        let mut extensions = Vec::new();
+        if soulbound {
+            extensions.push(ExtensionType::NonTransferable);
+        }
+        if metadata_pointer {
+            extensions.push(ExtensionType::MetadataPointer);
+        }
+        let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&extensions)?;
+
+        let config_key = self.config_account.key();
+        let seeds = &[
+            &b"rewards"[..],
+            config_key.as_ref(),
+            &[bumps.rewards_mint],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let rent = Rent::get()?.minimum_balance(space);
+        let funded = self.rewards_mint.lamports();
+        if funded == 0 {
+            create_account(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    CreateAccount {
+                        from: self.admin.to_account_info(),
+                        to: self.rewards_mint.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                rent,
+                space as u64,
+                &self.token_program.key(),
+            )?;
+        } else {
+            // Anyone can send lamports to the mint address, which makes create_account fail
+            if funded < rent {
+                transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        Transfer {
+                            from: self.admin.to_account_info(),
+                            to: self.rewards_mint.to_account_info(),
+                        },
+                    ),
+                    rent - funded,
+                )?;
+            }
+            allocate(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Allocate {
+                        account_to_allocate: self.rewards_mint.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                space as u64,
+            )?;
+            assign(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Assign {
+                        account_to_assign: self.rewards_mint.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                &self.token_program.key(),
+            )?;
+        }
+
+        // Extensions must be initialized before the mint itself
+        if soulbound {
+            non_transferable_mint_initialize(CpiContext::new(
+                self.token_program.to_account_info(),
+                NonTransferableMintInitialize {
+                    token_program_id: self.token_program.to_account_info(),
+                    mint: self.rewards_mint.to_account_info(),
+                },
+            ))?;
+        }
+
+        if metadata_pointer {
+            // Points to the Metaplex metadata account created by `create_rewards_metadata`
+            let (metadata_address, _) = mpl_token_metadata::accounts::Metadata::find_pda(&self.rewards_mint.key());
+            metadata_pointer_initialize(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    MetadataPointerInitialize {
+                        token_program_id: self.token_program.to_account_info(),
+                        mint: self.rewards_mint.to_account_info(),
+                    },
+                ),
+                Some(config_key),
+                Some(metadata_address),
+            )?;
+        }
+
+        initialize_mint2(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                InitializeMint2 {
+                    mint: self.rewards_mint.to_account_info(),
+                },
+            ),
+            REWARDS_DECIMALS,
+            &config_key,
+            None,
+        )?;
+
+        msg!("✅ Rewards mint created (soulbound: {}, metadata pointer: {})", soulbound, metadata_pointer);
+
+        Ok(())
+    }
+}
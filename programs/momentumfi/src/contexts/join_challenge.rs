@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{Challenge, ChallengeEntry, Config, GoalAccount, UserAccount};
+use crate::utils::open_goal_slot;
+
+#[derive(Accounts)]
+#[instruction(goal_number: u64)]
+pub struct JoinChallenge<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_account", user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge", config.key().as_ref(), &challenge.challenge_id.to_le_bytes()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    // One entry per user and challenge prevents joining twice
+    #[account(
+        init,
+        payer = user,
+        space = ChallengeEntry::INIT_SPACE + 8,
+        seeds = [b"challenge_entry", challenge.key().as_ref(), user_account.key().as_ref()],
+        bump,
+    )]
+    pub challenge_entry: Account<'info, ChallengeEntry>,
+
+    #[account(
+        init,
+        payer = user,
+        space = GoalAccount::INIT_SPACE,
+        seeds = [b"goal_account", user_account.key().as_ref(), &goal_number.to_le_bytes()],
+        bump,
+    )]
+    pub goal_account: Account<'info, GoalAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> JoinChallenge<'info> {
+    pub fn join_challenge(&mut self, goal_number: u64, bumps: &JoinChallengeBumps) -> Result<()> {
+        let clock = Clock::get()?;
+        let challenge = &mut self.challenge;
+        require!(
+            clock.unix_timestamp >= challenge.entry_start && clock.unix_timestamp <= challenge.entry_end,
+            MomentumFiError::ChallengeEntryClosed
+        );
+
+        // The goal is evaluated like any other goal of the same kind, the challenge only provides its parameters
+        self.goal_account.set_inner(GoalAccount {
+            user: self.user.key(),
+            total_points: 0,
+            creation_timestamp: clock.unix_timestamp,
+            target_usd: challenge.target_usd,
+            deadline: challenge.deadline,
+            last_daily_reward_timestamp: 0,
+            goal_number,
+            completed: false,
+            first_completed_bonus: false,
+            kind: challenge.kind,
+            growth_bps: 0,
+            baseline_usd: self.user_account.usd_balance,
+            baseline_sol: self.user_account.sol_balance,
+            breached: false,
+            breach_timestamp: 0,
+            breach_usd_balance: 0,
+            hold_days: 0,
+            streak_start_timestamp: 0,
+            streak_days: 0,
+            daily_streak: 0,
+            target_sol: challenge.target_sol,
+            challenge: challenge.key(),
+            challenge_reward_points: challenge.reward_points,
+            stake_lamports: 0,
+            completed_timestamp: 0,
+            vault_lamports: 0,
+            guardian: Pubkey::default(),
+            quest: Pubkey::default(),
+            quest_step: 0,
+            first_completed_points_override: 0,
+            daily_points_override: 0,
+            last_hold_observation: 0,
+            bump: bumps.goal_account,
+            _padding: [0; 2],
+        });
+
+        self.challenge_entry.set_inner(ChallengeEntry {
+            challenge: challenge.key(),
+            user: self.user.key(),
+            goal_number,
+            bump: bumps.challenge_entry,
+        });
+
+        challenge.participants = challenge.participants.checked_add(1).ok_or(MomentumFiError::ArithmeticOverflow)?;
+        open_goal_slot(&mut self.user_account, &self.config)?;
+        self.user_account.goal_count += 1;
+
+        msg!("Joined challenge {} with goal {}", challenge.challenge_id, goal_number);
+
+        Ok(())
+    }
+}
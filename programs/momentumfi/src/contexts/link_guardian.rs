@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{GuardianLink, UserAccount};
+
+// Both wallets sign, the dependent's signature is the one-time consent
+#[derive(Accounts)]
+pub struct LinkGuardian<'info> {
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    pub dependent: Signer<'info>,
+
+    #[account(
+        seeds = [b"user_account", dependent.key().as_ref()],
+        bump = dependent_account.bump,
+    )]
+    pub dependent_account: Account<'info, UserAccount>,
+
+    #[account(
+        init,
+        payer = guardian,
+        space = GuardianLink::INIT_SPACE + 8,
+        seeds = [b"guardian_link", dependent_account.key().as_ref()],
+        bump,
+    )]
+    pub guardian_link: Account<'info, GuardianLink>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LinkGuardian<'info> {
+    pub fn link_guardian(&mut self, rewards_to_guardian: bool, bumps: &LinkGuardianBumps) -> Result<()> {
+        require!(self.guardian.key() != self.dependent.key(), MomentumFiError::InvalidGuardian);
+
+        self.guardian_link.set_inner(GuardianLink {
+            guardian: self.guardian.key(),
+            dependent: self.dependent.key(),
+            rewards_to_guardian,
+            bump: bumps.guardian_link,
+        });
+
+        msg!("Guardian {} linked to {}, rewards to guardian: {}", self.guardian.key(), self.dependent.key(), rewards_to_guardian);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use anchor_spl::token::ID as TOKEN_PROGRAM_ID;
+
+use crate::contexts::DEFAULT_POINTS_CONVERSION_RATE;
+use crate::errors::MomentumFiError;
+use crate::state::{Config, GoalAccount, LegacyConfig, LegacyGoalAccount, LegacyUserAccount, UserAccount};
+
+// The config is read by hand since the current layout can't deserialize an account of the first deployment
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Owner, discriminator, size and authority are checked in the handler
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Migrates a user or goal account of the first deployment, once the config itself was migrated
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.config_bump,
+        constraint = config.authority == admin.key() @ MomentumFiError::UnauthorizedAccess,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Owner, discriminator and size are checked in the handler
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateConfig<'info> {
+    pub fn migrate_config(&mut self) -> Result<()> {
+        let legacy = {
+            let data = self.config.try_borrow_data()?;
+            require!(
+                *self.config.owner == crate::ID
+                    && data.len() == LegacyConfig::INIT_SPACE + 8
+                    && data[0..8] == Config::DISCRIMINATOR,
+                MomentumFiError::InvalidMigration
+            );
+            LegacyConfig::try_from_slice(&data[8..])?
+        };
+        require!(legacy.authority == self.admin.key(), MomentumFiError::UnauthorizedAccess);
+
+        // The first deployment created a legacy token mint without extensions
+        let config = Config {
+            authority: legacy.authority,
+            daily_points: legacy.daily_points,
+            first_completed_points: legacy.first_completed_points,
+            sol_price: legacy.sol_price,
+            price_last_updated: legacy.price_last_updated,
+            rewards_bump: legacy.rewards_bump,
+            config_bump: legacy.config_bump,
+            rewards_token_program: TOKEN_PROGRAM_ID,
+            points_conversion_rate: DEFAULT_POINTS_CONVERSION_RATE,
+            treasury: legacy.authority,
+            ..Config::default()
+        };
+        write_migrated(&self.config, &self.admin, &self.system_program, Config::INIT_SPACE + 8, &config)?;
+
+        msg!("Config migrated to the current layout");
+
+        Ok(())
+    }
+}
+
+impl<'info> MigrateAccount<'info> {
+    pub fn migrate_account(&mut self) -> Result<()> {
+        require!(*self.account.owner == crate::ID, MomentumFiError::InvalidMigration);
+
+        let (discriminator, len) = {
+            let data = self.account.try_borrow_data()?;
+            require!(data.len() >= 8, MomentumFiError::InvalidMigration);
+            (data[0..8].to_vec(), data.len())
+        };
+
+        if discriminator == UserAccount::DISCRIMINATOR && len == LegacyUserAccount::INIT_SPACE + 8 {
+            let legacy = LegacyUserAccount::try_from_slice(&self.account.try_borrow_data()?[8..])?;
+            let user_account = UserAccount {
+                owner: legacy.owner,
+                total_points: legacy.total_points,
+                claimable_rewards: legacy.claimable_rewards,
+                sol_balance: legacy.sol_balance,
+                usd_balance: legacy.usd_balance,
+                goal_count: legacy.goal_count,
+                bump: legacy.bump,
+                ..UserAccount::default()
+            };
+            write_migrated(&self.account, &self.admin, &self.system_program, UserAccount::INIT_SPACE + 8, &user_account)?;
+            msg!("User account of {} migrated", user_account.owner);
+        } else if discriminator == GoalAccount::DISCRIMINATOR && len == LegacyGoalAccount::INIT_SPACE + 8 {
+            // Goals of the first deployment are all USD target goals
+            let legacy = LegacyGoalAccount::try_from_slice(&self.account.try_borrow_data()?[8..])?;
+            let goal_account = GoalAccount {
+                user: legacy.user,
+                total_points: legacy.total_points,
+                creation_timestamp: legacy.creation_timestamp,
+                target_usd: legacy.target_usd,
+                deadline: legacy.deadline,
+                last_daily_reward_timestamp: legacy.last_daily_reward_timestamp,
+                goal_number: legacy.goal_number,
+                completed: legacy.completed,
+                first_completed_bonus: legacy.first_completed_bonus,
+                bump: legacy.bump,
+                ..GoalAccount::default()
+            };
+            // GoalAccount::INIT_SPACE already includes the discriminator
+            write_migrated(&self.account, &self.admin, &self.system_program, GoalAccount::INIT_SPACE, &goal_account)?;
+            msg!("Goal {} of {} migrated", goal_account.goal_number, goal_account.user);
+        } else {
+            return err!(MomentumFiError::InvalidMigration);
+        }
+
+        Ok(())
+    }
+}
+
+// Grows the account to the current layout, tops up its rent from the admin and writes the migrated state
+fn write_migrated<'info, T: AccountSerialize>(
+    account: &UncheckedAccount<'info>,
+    admin: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+    state: &T,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let lamports = account.lamports();
+    if rent > lamports {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: admin.to_account_info(),
+                    to: account.to_account_info(),
+                },
+            ),
+            rent - lamports,
+        )?;
+    }
+
+    account.to_account_info().realloc(space, true)?;
+    state.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
+}
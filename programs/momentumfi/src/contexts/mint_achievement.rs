@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{
+    create_master_edition_v3, create_metadata_accounts_v3,
+    mpl_token_metadata::types::DataV2,
+    CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata,
+};
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+
+use crate::errors::MomentumFiError;
+use crate::state::{AchievementRecord, Config, Milestone, UserAccount};
+
+pub const ACHIEVEMENT_SYMBOL: &str = "MOMO";
+
+#[derive(Accounts)]
+#[instruction(milestone: Milestone)]
+pub struct MintAchievement<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"user_account", user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    // One record per user and milestone prevents minting the same achievement twice
+    #[account(
+        init,
+        payer = user,
+        space = AchievementRecord::INIT_SPACE + 8,
+        seeds = [b"achievement", user_account.key().as_ref(), &[milestone as u8]],
+        bump,
+    )]
+    pub achievement_record: Account<'info, AchievementRecord>,
+
+    #[account(
+        init,
+        payer = user,
+        seeds = [b"achievement_mint", user_account.key().as_ref(), &[milestone as u8]],
+        bump,
+        mint::decimals = 0,
+        mint::authority = config,
+        mint::freeze_authority = config,
+    )]
+    pub achievement_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = user,
+        associated_token::mint = achievement_mint,
+        associated_token::authority = user,
+    )]
+    pub user_achievement_ata: Account<'info, TokenAccount>,
+
+    /// CHECK: Metaplex metadata PDA of the achievement mint. Created and validated by the token metadata program.
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), achievement_mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex master edition PDA of the achievement mint. Created and validated by the token metadata program.
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), achievement_mint.key().as_ref(), b"edition"],
+        seeds::program = token_metadata_program.key(),
+        bump,
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub token_program: Program<'info, Token>, // Master editions require the legacy token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> MintAchievement<'info> {
+    pub fn mint_achievement(&mut self, milestone: Milestone, bumps: &MintAchievementBumps) -> Result<()> {
+        let reached = match milestone {
+            Milestone::FirstGoal => self.user_account.completed_goals >= 1,
+            Milestone::TenGoals => self.user_account.completed_goals >= 10,
+            Milestone::ThirtyDayStreak => self.user_account.longest_streak >= 30,
+        };
+        require!(reached, MomentumFiError::MilestoneNotReached);
+
+        let seeds = &[
+            &b"config"[..],
+            &[self.config.config_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // Mint the single token of the NFT to the user
+        mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                MintTo {
+                    mint: self.achievement_mint.to_account_info(),
+                    to: self.user_achievement_ata.to_account_info(),
+                    authority: self.config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+
+        let data = DataV2 {
+            name: milestone.name().to_string(),
+            symbol: ACHIEVEMENT_SYMBOL.to_string(),
+            uri: format!("{}{}.json", self.config.achievement_base_uri, milestone.slug()),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        };
+
+        create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                self.token_metadata_program.to_account_info(),
+                CreateMetadataAccountsV3 {
+                    metadata: self.metadata.to_account_info(),
+                    mint: self.achievement_mint.to_account_info(),
+                    mint_authority: self.config.to_account_info(),
+                    payer: self.user.to_account_info(),
+                    update_authority: self.config.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                    rent: self.rent.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            data,
+            true,
+            true,
+            None,
+        )?;
+
+        // A master edition with a max supply of 0 makes the NFT unique
+        create_master_edition_v3(
+            CpiContext::new_with_signer(
+                self.token_metadata_program.to_account_info(),
+                CreateMasterEditionV3 {
+                    edition: self.master_edition.to_account_info(),
+                    mint: self.achievement_mint.to_account_info(),
+                    update_authority: self.config.to_account_info(),
+                    mint_authority: self.config.to_account_info(),
+                    payer: self.user.to_account_info(),
+                    metadata: self.metadata.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                    rent: self.rent.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            Some(0),
+        )?;
+
+        self.achievement_record.set_inner(AchievementRecord {
+            owner: self.user.key(),
+            milestone,
+            mint: self.achievement_mint.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+            bump: bumps.achievement_record,
+        });
+
+        msg!("Achievement {:?} minted", milestone);
+
+        Ok(())
+    }
+}
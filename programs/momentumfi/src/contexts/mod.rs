@@ -10,11 +10,101 @@ pub mod create_goal;
 pub use delete_goal::*;
 pub mod delete_goal;
 
+pub use reclaim_goal_stake::*;
+pub mod reclaim_goal_stake;
+
+pub use forfeit_goal_stake::*;
+pub mod forfeit_goal_stake;
+
+pub use deposit_to_vault::*;
+pub mod deposit_to_vault;
+
+pub use withdraw_from_vault::*;
+pub mod withdraw_from_vault;
+
+pub use create_shared_goal::*;
+pub mod create_shared_goal;
+
+pub use contribute_shared_goal::*;
+pub mod contribute_shared_goal;
+
+pub use update_shared_goal::*;
+pub mod update_shared_goal;
+
+pub use withdraw_shared_contribution::*;
+pub mod withdraw_shared_contribution;
+
+pub use link_guardian::*;
+pub mod link_guardian;
+
+pub use guardian_goal::*;
+pub mod guardian_goal;
+
+pub use create_quest::*;
+pub mod create_quest;
+
+pub use start_quest::*;
+pub mod start_quest;
+
+pub use create_goal_template::*;
+pub mod create_goal_template;
+
+pub use create_goal_from_template::*;
+pub mod create_goal_from_template;
+
+pub use create_challenge::*;
+pub mod create_challenge;
+
+pub use join_challenge::*;
+pub mod join_challenge;
+
 pub use claim_rewards::*;
 pub mod claim_rewards;
 
+pub use withdraw_vested::*;
+pub mod withdraw_vested;
+
+pub use stake_rewards::*;
+pub mod stake_rewards;
+
+pub use unstake_rewards::*;
+pub mod unstake_rewards;
+
+pub use set_perk::*;
+pub mod set_perk;
+
+pub use redeem_perk::*;
+pub mod redeem_perk;
+
+pub use use_deadline_extension::*;
+pub mod use_deadline_extension;
+
+pub use grant_streak_freezes::*;
+pub mod grant_streak_freezes;
+
+pub use mint_achievement::*;
+pub mod mint_achievement;
+
+pub use init_leaderboard::*;
+pub mod init_leaderboard;
+
+pub use start_season::*;
+pub mod start_season;
+
+pub use claim_season_reward::*;
+pub mod claim_season_reward;
+
 pub use update_reward_points_admin::*;
 pub mod update_reward_points_admin;
 
 pub use update_reward_points_user::*;
-pub mod update_reward_points_user;
\ No newline at end of file
+pub mod update_reward_points_user;
+
+pub use update_config::*;
+pub mod update_config;
+
+pub use migrate_accounts::*;
+pub mod migrate_accounts;
+
+pub use rewards_metadata::*;
+pub mod rewards_metadata;
\ No newline at end of file
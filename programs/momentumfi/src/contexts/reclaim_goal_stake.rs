@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{GoalAccount, UserAccount};
+use crate::utils::{completed_in_time, release_goal_stake};
+
+#[derive(Accounts)]
+pub struct ReclaimGoalStake<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"user_account", user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        has_one = user @ MomentumFiError::UnauthorizedAccess,
+        seeds = [b"goal_account", user_account.key().as_ref(), &goal_account.goal_number.to_le_bytes()],
+        bump = goal_account.bump,
+    )]
+    pub goal_account: Account<'info, GoalAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"goal_stake", goal_account.key().as_ref()],
+        bump,
+    )]
+    pub goal_stake: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReclaimGoalStake<'info> {
+    pub fn reclaim_goal_stake(&mut self, bumps: &ReclaimGoalStakeBumps) -> Result<()> {
+        require!(self.goal_account.stake_lamports > 0, MomentumFiError::NoGoalStake);
+        require!(completed_in_time(&self.goal_account), MomentumFiError::GoalNotCompletedInTime);
+
+        let amount = self.goal_stake.lamports();
+        release_goal_stake(&self.goal_stake, self.user.to_account_info(), &self.goal_account, bumps.goal_stake, &self.system_program)?;
+        self.goal_account.stake_lamports = 0;
+
+        msg!("Goal stake of {} lamports returned", amount);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{burn, Burn, Mint, TokenAccount, TokenInterface};
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, PerkCatalog, PerkKind, UserAccount};
+
+#[derive(Accounts)]
+pub struct RedeemPerk<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_account", user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = rewards_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_rewards_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"perk_catalog", config.key().as_ref()],
+        bump = perk_catalog.bump,
+    )]
+    pub perk_catalog: Account<'info, PerkCatalog>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"rewards", config.key().as_ref()],
+        bump = config.rewards_bump,
+        mint::token_program = token_program,
+    )]
+    pub rewards_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> RedeemPerk<'info> {
+    pub fn redeem_perk(&mut self, kind: PerkKind) -> Result<()> {
+        let perk = self.perk_catalog.perks[kind as usize];
+        require!(perk.enabled, MomentumFiError::PerkUnavailable);
+
+        let cpi_accounts = Burn {
+            mint: self.rewards_mint.to_account_info(),
+            from: self.user_rewards_ata.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        burn(cpi_context, perk.cost)?;
+
+        let user_account = &mut self.user_account;
+        let balance = match kind {
+            PerkKind::ExtraGoalSlot => &mut user_account.extra_goal_slots,
+            PerkKind::StreakFreeze => &mut user_account.streak_freezes,
+            PerkKind::DeadlineExtension => &mut user_account.deadline_extensions,
+        };
+        *balance = balance.checked_add(1).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+        msg!("Redeemed perk {:?} for {} reward tokens", kind, perk.cost);
+
+        Ok(())
+    }
+}
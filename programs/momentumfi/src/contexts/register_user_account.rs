@@ -23,6 +23,9 @@ pub struct RegisterUserAccount<'info> {
     )]
     pub config_account: Account<'info, Config>,
 
+    // Optional, the user account of whoever invited this user
+    pub referrer_account: Option<Account<'info, UserAccount>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -31,6 +34,14 @@ impl<'info> RegisterUserAccount<'info> {
         let sol_balance = self.user.lamports();
         let usd_balance = (sol_balance as u128 * self.config_account.sol_price as u128 / 1_000_000_000) as u64;
 
+        let referrer = match &self.referrer_account {
+            Some(referrer_account) => {
+                require!(referrer_account.owner != self.user.key(), MomentumFiError::SelfReferral);
+                referrer_account.owner
+            }
+            None => Pubkey::default(),
+        };
+
         self.user_account.set_inner(UserAccount { 
             owner: self.user.key(),
             total_points: 0, 
@@ -38,6 +49,21 @@ impl<'info> RegisterUserAccount<'info> {
             sol_balance: sol_balance,
             usd_balance: usd_balance,
             goal_count: 0,
+            daily_streak: 0,
+            longest_streak: 0,
+            last_streak_timestamp: 0,
+            extra_goal_slots: 0,
+            streak_freezes: 0,
+            deadline_extensions: 0,
+            completed_goals: 0,
+            referrer,
+            referral_bonus_paid: false,
+            season_number: 0,
+            season_points: 0,
+            last_season_number: 0,
+            last_season_points: 0,
+            open_goals: 0,
+            referral_qualified: false,
             bump: bumps.user_account 
         });
 
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{
+    create_metadata_accounts_v3, update_metadata_accounts_v2,
+    mpl_token_metadata::types::DataV2,
+    CreateMetadataAccountsV3, Metadata, UpdateMetadataAccountsV2,
+};
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::MomentumFiError;
+use crate::state::Config;
+
+pub const MAX_METADATA_NAME_LENGTH: usize = 32;
+pub const MAX_METADATA_SYMBOL_LENGTH: usize = 10;
+pub const MAX_METADATA_URI_LENGTH: usize = 200;
+
+#[derive(Accounts)]
+pub struct RewardsMetadata<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.config_bump,
+        constraint = config.authority == admin.key() @ MomentumFiError::UnauthorizedAccess,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"rewards", config.key().as_ref()],
+        bump = config.rewards_bump,
+    )]
+    pub rewards_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Metaplex metadata PDA of the rewards mint. Created and validated by the token metadata program.
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), rewards_mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> RewardsMetadata<'info> {
+    pub fn create_rewards_metadata(&mut self, name: String, symbol: String, uri: String) -> Result<()> {
+        let data = Self::metadata_data(name, symbol, uri)?;
+
+        let cpi_accounts = CreateMetadataAccountsV3 {
+            metadata: self.metadata.to_account_info(),
+            mint: self.rewards_mint.to_account_info(),
+            mint_authority: self.config.to_account_info(),
+            payer: self.admin.to_account_info(),
+            update_authority: self.config.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            rent: self.rent.to_account_info(),
+        };
+
+        let seeds = &[
+            &b"config"[..],
+            &[self.config.config_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_context = CpiContext::new_with_signer(self.token_metadata_program.to_account_info(), cpi_accounts, signer_seeds);
+        create_metadata_accounts_v3(cpi_context, data, true, true, None)?;
+
+        msg!("Rewards mint metadata created");
+
+        Ok(())
+    }
+
+    pub fn update_rewards_metadata(&mut self, name: String, symbol: String, uri: String) -> Result<()> {
+        let data = Self::metadata_data(name, symbol, uri)?;
+
+        let cpi_accounts = UpdateMetadataAccountsV2 {
+            metadata: self.metadata.to_account_info(),
+            update_authority: self.config.to_account_info(),
+        };
+
+        let seeds = &[
+            &b"config"[..],
+            &[self.config.config_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_context = CpiContext::new_with_signer(self.token_metadata_program.to_account_info(), cpi_accounts, signer_seeds);
+        update_metadata_accounts_v2(cpi_context, None, Some(data), None, None)?;
+
+        msg!("Rewards mint metadata updated");
+
+        Ok(())
+    }
+
+    fn metadata_data(name: String, symbol: String, uri: String) -> Result<DataV2> {
+        require!(name.len() <= MAX_METADATA_NAME_LENGTH, MomentumFiError::NameTooLong);
+        require!(symbol.len() <= MAX_METADATA_SYMBOL_LENGTH, MomentumFiError::SymbolTooLong);
+        require!(uri.len() <= MAX_METADATA_URI_LENGTH, MomentumFiError::UriTooLong);
+
+        Ok(DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        })
+    }
+}
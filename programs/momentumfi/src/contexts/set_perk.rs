@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, Perk, PerkCatalog, PerkKind};
+
+#[derive(Accounts)]
+pub struct SetPerk<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.config_bump,
+        constraint = config.authority == admin.key() @ MomentumFiError::UnauthorizedAccess,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PerkCatalog::INIT_SPACE + 8,
+        seeds = [b"perk_catalog", config.key().as_ref()],
+        bump,
+    )]
+    pub perk_catalog: Account<'info, PerkCatalog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetPerk<'info> {
+    pub fn set_perk(&mut self, kind: PerkKind, cost: u64, enabled: bool, bumps: &SetPerkBumps) -> Result<()> {
+        require!(!enabled || cost > 0, MomentumFiError::InvalidPerkCost);
+
+        self.perk_catalog.perks[kind as usize] = Perk { cost, enabled };
+        self.perk_catalog.bump = bumps.perk_catalog;
+
+        msg!("Perk {:?} set: cost {} enabled {}", kind, cost, enabled);
+
+        Ok(())
+    }
+}
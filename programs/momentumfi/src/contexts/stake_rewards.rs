@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, StakeAccount};
+use crate::utils::SECONDS_PER_DAY;
+
+pub const MAX_LOCK_DURATION: i64 = 4 * 365 * SECONDS_PER_DAY; // 4 years
+
+#[derive(Accounts)]
+pub struct StakeRewards<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = rewards_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_rewards_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = StakeAccount::INIT_SPACE + 8,
+        seeds = [b"stake", user.key().as_ref()],
+        bump,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [b"stake_vault", config.key().as_ref()],
+        bump,
+        token::mint = rewards_mint,
+        token::authority = config,
+        token::token_program = token_program,
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>, // Program vault holding all staked reward tokens
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"rewards", config.key().as_ref()],
+        bump = config.rewards_bump,
+        mint::token_program = token_program,
+    )]
+    pub rewards_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StakeRewards<'info> {
+    pub fn stake_rewards(&mut self, amount: u64, lock_duration: i64, bumps: &StakeRewardsBumps) -> Result<()> {
+        // Staking moves tokens into the vault, which a non-transferable mint does not allow
+        require!(!self.config.soulbound, MomentumFiError::StakingUnavailable);
+        require!(amount > 0, MomentumFiError::InvalidStakeAmount);
+        require!((0..=MAX_LOCK_DURATION).contains(&lock_duration), MomentumFiError::InvalidStakeAmount);
+
+        let cpi_accounts = TransferChecked {
+            from: self.user_rewards_ata.to_account_info(),
+            mint: self.rewards_mint.to_account_info(),
+            to: self.stake_vault.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        transfer_checked(cpi_context, amount, self.rewards_mint.decimals)?;
+
+        // Staking again never shortens an existing lock
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        let lock_until = current_timestamp
+            .checked_add(lock_duration)
+            .ok_or(MomentumFiError::ArithmeticOverflow)?
+            .max(self.stake_account.lock_until);
+        let staked_amount = self.stake_account.amount.checked_add(amount).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+        self.stake_account.set_inner(StakeAccount {
+            owner: self.user.key(),
+            amount: staked_amount,
+            lock_start: current_timestamp,
+            lock_until,
+            lock_duration: lock_until - current_timestamp,
+            bump: bumps.stake_account,
+        });
+
+        msg!("Staked {} reward tokens, {} locked until {}", amount, staked_amount, lock_until);
+
+        Ok(())
+    }
+}
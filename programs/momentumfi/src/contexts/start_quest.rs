@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Config, GoalAccount, QuestTemplate, UserAccount};
+use crate::utils::{apply_quest_step, open_goal_slot};
+
+#[derive(Accounts)]
+#[instruction(goal_number: u64)]
+pub struct StartQuest<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_account", user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        seeds = [b"quest", config.key().as_ref(), &quest_template.quest_id.to_le_bytes()],
+        bump = quest_template.bump,
+    )]
+    pub quest_template: Account<'info, QuestTemplate>,
+
+    #[account(
+        init,
+        payer = user,
+        space = GoalAccount::INIT_SPACE,
+        seeds = [b"goal_account", user_account.key().as_ref(), &goal_number.to_le_bytes()],
+        bump,
+    )]
+    pub goal_account: Account<'info, GoalAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StartQuest<'info> {
+    pub fn start_quest(&mut self, goal_number: u64, bumps: &StartQuestBumps) -> Result<()> {
+        let mut goal_account = GoalAccount {
+            user: self.user.key(),
+            goal_number,
+            quest: self.quest_template.key(),
+            bump: bumps.goal_account,
+            ..GoalAccount::default()
+        };
+        apply_quest_step(&mut goal_account, &self.quest_template, 0, &self.user_account, Clock::get()?.unix_timestamp);
+        self.goal_account.set_inner(goal_account);
+
+        open_goal_slot(&mut self.user_account, &self.config)?;
+        self.user_account.goal_count += 1;
+
+        msg!("Quest {} started with goal {}", self.quest_template.quest_id, goal_number);
+
+        Ok(())
+    }
+}
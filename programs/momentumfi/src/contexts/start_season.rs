@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, Season};
+
+#[derive(Accounts)]
+pub struct StartSeason<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.config_bump,
+        constraint = config.authority == admin.key() @ MomentumFiError::UnauthorizedAccess,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Only needed once a season was scheduled, it has to be over before the next one is scheduled
+    #[account(
+        seeds = [b"season", config.key().as_ref(), &config.current_season.to_le_bytes()],
+        bump = current_season.bump,
+    )]
+    pub current_season: Option<Account<'info, Season>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Season::INIT_SPACE + 8,
+        seeds = [b"season", config.key().as_ref(), &(config.current_season + 1).to_le_bytes()],
+        bump,
+    )]
+    pub next_season: Account<'info, Season>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StartSeason<'info> {
+    pub fn start_season(&mut self, start_timestamp: i64, end_timestamp: i64, reward_pool: u64, bumps: &StartSeasonBumps) -> Result<()> {
+        require!(end_timestamp > start_timestamp, MomentumFiError::InvalidSeasonSchedule);
+
+        // The reward updates record points into config.current_season, so it can only move on once that season ended
+        if self.config.current_season > 0 {
+            let current_season = self.current_season.as_ref().ok_or(MomentumFiError::SeasonInProgress)?;
+            require!(Clock::get()?.unix_timestamp >= current_season.end_timestamp, MomentumFiError::SeasonInProgress);
+            require!(start_timestamp >= current_season.end_timestamp, MomentumFiError::SeasonInProgress);
+        }
+
+        let season_number = self.config.current_season + 1;
+        self.next_season.set_inner(Season {
+            season_number,
+            start_timestamp,
+            end_timestamp,
+            reward_pool,
+            total_points: 0,
+            distributed: 0,
+            bump: bumps.next_season,
+        });
+        self.config.current_season = season_number;
+
+        msg!("Season {} scheduled from {} to {} with a pool of {}", season_number, start_timestamp, end_timestamp, reward_pool);
+
+        Ok(())
+    }
+}
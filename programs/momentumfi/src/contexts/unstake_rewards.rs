@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, StakeAccount};
+
+#[derive(Accounts)]
+pub struct UnstakeRewards<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = rewards_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_rewards_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        close = user, // Refund rent to the user once everything is unstaked
+        seeds = [b"stake", user.key().as_ref()],
+        bump = stake_account.bump,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"stake_vault", config.key().as_ref()],
+        bump,
+        token::mint = rewards_mint,
+        token::authority = config,
+        token::token_program = token_program,
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"rewards", config.key().as_ref()],
+        bump = config.rewards_bump,
+        mint::token_program = token_program,
+    )]
+    pub rewards_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UnstakeRewards<'info> {
+    pub fn unstake_rewards(&mut self) -> Result<()> {
+        require!(Clock::get()?.unix_timestamp >= self.stake_account.lock_until, MomentumFiError::StakeLocked);
+
+        let cpi_accounts = TransferChecked {
+            from: self.stake_vault.to_account_info(),
+            mint: self.rewards_mint.to_account_info(),
+            to: self.user_rewards_ata.to_account_info(),
+            authority: self.config.to_account_info(),
+        };
+
+        let seeds = &[
+            &b"config"[..],
+            &[self.config.config_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_context = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+        transfer_checked(cpi_context, self.stake_account.amount, self.rewards_mint.decimals)?;
+
+        msg!("Unstaked {} reward tokens", self.stake_account.amount);
+
+        Ok(())
+    }
+}
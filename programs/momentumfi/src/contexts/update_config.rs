@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, StreakMultiplier, STREAK_TIERS, MAX_ACHIEVEMENT_URI_LENGTH};
+use crate::utils::BASIS_POINTS;
+
+pub const MAX_STREAK_MULTIPLIER_BPS: u16 = 50_000; // 5x
+pub const MAX_DIFFICULTY_BPS: u16 = 50_000; // 5x
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.config_bump,
+        constraint = config.authority == admin.key() @ MomentumFiError::UnauthorizedAccess,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateConfig<'info> {
+    pub fn set_streak_multipliers(&mut self, streak_multipliers: [StreakMultiplier; STREAK_TIERS]) -> Result<()> {
+        let mut previous_min_days = 0;
+        for tier in streak_multipliers.iter().filter(|tier| tier.min_days > 0) {
+            require!(tier.min_days > previous_min_days, MomentumFiError::InvalidStreakMultiplier);
+            require!(
+                tier.multiplier_bps as u128 >= BASIS_POINTS && tier.multiplier_bps <= MAX_STREAK_MULTIPLIER_BPS,
+                MomentumFiError::InvalidStreakMultiplier
+            );
+            previous_min_days = tier.min_days;
+        }
+
+        self.config.streak_multipliers = streak_multipliers;
+        msg!("Streak multipliers updated: {:?}", streak_multipliers);
+
+        Ok(())
+    }
+
+    pub fn set_difficulty_curve(&mut self, gap_weight_bps: u16, reference_days: u16, min_difficulty_bps: u16, max_difficulty_bps: u16) -> Result<()> {
+        // Easy goals can pay less than the flat bonus, hard goals at most MAX_DIFFICULTY_BPS
+        require!(
+            max_difficulty_bps == 0
+                || (min_difficulty_bps as u128 <= BASIS_POINTS
+                    && max_difficulty_bps as u128 >= BASIS_POINTS
+                    && max_difficulty_bps <= MAX_DIFFICULTY_BPS),
+            MomentumFiError::InvalidDifficultyCurve
+        );
+
+        self.config.difficulty_gap_weight_bps = gap_weight_bps;
+        self.config.difficulty_reference_days = reference_days;
+        self.config.min_difficulty_bps = min_difficulty_bps;
+        self.config.max_difficulty_bps = max_difficulty_bps;
+
+        msg!(
+            "Difficulty curve updated: gap weight {} bps, reference {} days, range {}-{} bps",
+            gap_weight_bps, reference_days, min_difficulty_bps, max_difficulty_bps
+        );
+
+        Ok(())
+    }
+
+    // Restarts the epochs from now, the supply already minted keeps counting against the cap
+    pub fn set_emission_schedule(&mut self, max_supply: u64, epoch_duration: i64, epoch_emission_budget: u64, halving_interval: u16) -> Result<()> {
+        require!(max_supply == 0 || max_supply >= self.config.total_minted, MomentumFiError::InvalidEmissionSchedule);
+        require!(epoch_duration >= 0, MomentumFiError::InvalidEmissionSchedule);
+        require!(epoch_duration == 0 || epoch_emission_budget > 0, MomentumFiError::InvalidEmissionSchedule);
+
+        self.config.max_supply = max_supply;
+        self.config.epoch_duration = epoch_duration;
+        self.config.epoch_emission_budget = epoch_emission_budget;
+        self.config.halving_interval = halving_interval;
+        self.config.emissions_start = Clock::get()?.unix_timestamp;
+        self.config.current_epoch = 0;
+        self.config.epoch_minted = 0;
+
+        msg!("Emission schedule updated: cap {} budget {} per {}s epoch, halving every {} epochs", max_supply, epoch_emission_budget, epoch_duration, halving_interval);
+
+        Ok(())
+    }
+
+    pub fn set_stake_boost(&mut self, stake_boost_unit: u64, stake_boost_bps_per_unit: u16, lock_boost_bps_per_day: u16, max_stake_boost_bps: u16) -> Result<()> {
+        self.config.stake_boost_unit = stake_boost_unit;
+        self.config.stake_boost_bps_per_unit = stake_boost_bps_per_unit;
+        self.config.lock_boost_bps_per_day = lock_boost_bps_per_day;
+        self.config.max_stake_boost_bps = max_stake_boost_bps;
+
+        msg!("Stake boost updated: {} bps per {} staked, {} bps per lock day, capped at {} bps", stake_boost_bps_per_unit, stake_boost_unit, lock_boost_bps_per_day, max_stake_boost_bps);
+
+        Ok(())
+    }
+
+    pub fn set_referral_bonus(&mut self, referral_bonus_points: u64) -> Result<()> {
+        self.config.referral_bonus_points = referral_bonus_points;
+        msg!("Referral bonus updated: {}", referral_bonus_points);
+
+        Ok(())
+    }
+
+    pub fn set_goal_slots(&mut self, goal_slots: u16) -> Result<()> {
+        self.config.goal_slots = goal_slots;
+        msg!("Goal slots updated: {}", goal_slots);
+
+        Ok(())
+    }
+
+    pub fn set_treasury(&mut self, treasury: Pubkey) -> Result<()> {
+        self.config.treasury = treasury;
+        msg!("Treasury updated: {}", treasury);
+
+        Ok(())
+    }
+
+    pub fn set_achievement_base_uri(&mut self, achievement_base_uri: String) -> Result<()> {
+        require!(achievement_base_uri.len() <= MAX_ACHIEVEMENT_URI_LENGTH, MomentumFiError::UriTooLong);
+
+        msg!("Achievement base URI updated: {}", achievement_base_uri);
+        self.config.achievement_base_uri = achievement_base_uri;
+
+        Ok(())
+    }
+
+    // Only affects future claims, running schedules keep the parameters they started with
+    pub fn set_vesting(&mut self, vesting_enabled: bool, vesting_cliff: i64, vesting_duration: i64) -> Result<()> {
+        // Vested rewards are transferred out of the escrow, which a non-transferable mint does not allow
+        require!(!(vesting_enabled && self.config.soulbound), MomentumFiError::VestingUnavailable);
+        require!(vesting_cliff >= 0 && vesting_cliff <= vesting_duration, MomentumFiError::InvalidVestingSchedule);
+        require!(!vesting_enabled || vesting_duration > 0, MomentumFiError::InvalidVestingSchedule);
+
+        self.config.vesting_enabled = vesting_enabled;
+        self.config.vesting_cliff = vesting_cliff;
+        self.config.vesting_duration = vesting_duration;
+
+        msg!("Vesting updated: enabled {} cliff {}s duration {}s", vesting_enabled, vesting_cliff, vesting_duration);
+
+        Ok(())
+    }
+
+    // Only affects future claims, accrued points stay points until they are claimed
+    pub fn set_points_conversion_rate(&mut self, points_conversion_rate: u64) -> Result<()> {
+        require!(points_conversion_rate > 0, MomentumFiError::InvalidConversionRate);
+
+        self.config.points_conversion_rate = points_conversion_rate;
+        msg!("Points conversion rate updated: {}", points_conversion_rate);
+
+        Ok(())
+    }
+}
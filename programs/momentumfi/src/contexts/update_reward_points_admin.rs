@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 use chrono::{NaiveDateTime, Timelike, Utc}; // Use `chrono` for time calculations
 
 use crate::errors::MomentumFiError;
-use crate::state::{UserAccount, GoalAccount, Config};
-use crate::utils::{update_sol_price, update_user_account, is_goal_completed};
+use crate::state::{UserAccount, GoalAccount, Config, StakeAccount, Leaderboard, Season, GuardianLink, QuestTemplate};
+use crate::utils::{update_sol_price, update_user_account, is_goal_completed, goal_balances, completion_points, award_points, pay_referral_bonus, qualifies_for_referral, update_leaderboard, advance_quest, rewards_to_guardian, award_guardian_points, record_season_points, next_streak, missed_days, daily_points_for_streak, stake_boost_bps, apply_boost};
 
 pub const TWENTY_FOUR_HOURS: u64 = 86_400;
 
@@ -31,6 +32,55 @@ pub struct UpdateRewardPointsAdmin<'info> {
 
     pub price_update: Account<'info, PriceUpdateV2>,
 
+    // Optional, boosts the earned points when the user has staked reward tokens
+    #[account(
+        seeds = [b"stake", user_account.owner.as_ref()],
+        bump = stake_account.bump,
+    )]
+    pub stake_account: Option<Account<'info, StakeAccount>>,
+
+    // Optional, receives the referral bonus once the user completed a goal
+    #[account(
+        mut,
+        seeds = [b"user_account", user_account.referrer.as_ref()],
+        bump = referrer_account.bump,
+    )]
+    pub referrer_account: Option<Account<'info, UserAccount>>,
+
+    // Optional, re-ranks the user and the referrer on the leaderboard
+    #[account(
+        mut,
+        seeds = [b"leaderboard", config.key().as_ref()],
+        bump = leaderboard.bump,
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+
+    // Optional, counts the earned points towards the current season
+    #[account(
+        mut,
+        seeds = [b"season", config.key().as_ref(), &config.current_season.to_le_bytes()],
+        bump = season.bump,
+    )]
+    pub season: Option<Account<'info, Season>>,
+
+    // Optional, required when guardian-managed goals are updated
+    #[account(
+        seeds = [b"guardian_link", user_account.key().as_ref()],
+        bump = guardian_link.bump,
+    )]
+    pub guardian_link: Option<Account<'info, GuardianLink>>,
+
+    // Optional, receives the points of guardian-managed goals when the guardian chose so
+    #[account(
+        mut,
+        seeds = [b"user_account", guardian_account.owner.as_ref()],
+        bump = guardian_account.bump,
+    )]
+    pub guardian_account: Option<Account<'info, UserAccount>>,
+
+    // Optional, required when a step of a quest goal completes
+    pub quest_template: Option<Account<'info, QuestTemplate>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -51,28 +101,67 @@ impl<'info> UpdateRewardPointsAdmin<'info> {
         let current_utc_time = naive_datetime.and_utc();
         let noon_utc = current_utc_time.date().and_hms_opt(12, 0, 0).ok_or(MomentumFiError::InvalidTimestamp)?; // 12:00 PM UTC
 
-        let mut new_reward_points = 0;
+        let boost_bps = stake_boost_bps(&ctx.accounts.config, ctx.accounts.stake_account.as_deref(), current_timestamp);
+        let mut new_reward_points: u64 = 0;
+        let mut guardian_points: u64 = 0;
+        let mut earned_daily_points = false;
+
+        // Days missed since the user's last rewarded day are bridged when enough streak freezes are available
+        let missed = missed_days(user_account.last_streak_timestamp, current_timestamp);
+        let bridged_days = if missed <= user_account.streak_freezes as i64 { missed as u16 } else { 0 };
         // Loop through remaining accounts and manually read/write GoalAccounts
         for account_info in ctx.remaining_accounts.iter() {
+            // Only goal accounts of this program can be updated
+            require!(account_info.owner == ctx.program_id, MomentumFiError::InvalidGoalAccount);
+
             // Get a copy of the original data with discriminator
             let mut goal_account_data = account_info.data.borrow_mut();
+            require!(goal_account_data[0..8] == GoalAccount::DISCRIMINATOR, MomentumFiError::InvalidGoalAccount);
             let discriminator = goal_account_data[0..8].to_vec();
 
             // Deserialize the account data (skipping the 8-byte anchor discriminator)
             let mut goal_account = GoalAccount::try_from_slice(&goal_account_data[8..])?;
+
+            // Goals of other users would be evaluated against this user's balances
+            require!(goal_account.user == user_account.owner, MomentumFiError::InvalidGoalAccount);
+            // Guardian-managed goals are only updated through the matching guardian link
+            let to_guardian = rewards_to_guardian(&goal_account, ctx.accounts.guardian_link.as_deref())?;
             
-            goal_account.completed = is_goal_completed(user_account.usd_balance, &mut goal_account);
+            let (usd_balance, sol_balance) = goal_balances(user_account, &goal_account, ctx.accounts.config.sol_price);
+            goal_account.completed = is_goal_completed(usd_balance, sol_balance, &mut goal_account, current_timestamp);
+            let mut goal_points: u64 = 0;
             
             if goal_account.completed {
                 if !goal_account.first_completed_bonus {
-                    new_reward_points += ctx.accounts.config.first_completed_points; // Add points as one-time reward for each completed goal
                     goal_account.first_completed_bonus = true; // Mark that this goal has received bonus
+                    goal_account.completed_timestamp = current_timestamp; // Set first, the challenge reward depends on it
+                    goal_points = completion_points(&ctx.accounts.config, &goal_account)?; // Add points as one-time reward for each completed goal
+                    user_account.completed_goals = user_account.completed_goals.checked_add(1).ok_or(MomentumFiError::ArithmeticOverflow)?;
+                    user_account.referral_qualified |= qualifies_for_referral(&goal_account);
+
+                    // Quest goals move on to their next step, the last step pays the chain bonus
+                    let chain_bonus = advance_quest(&mut goal_account, ctx.accounts.quest_template.as_ref(), user_account, current_timestamp)?;
+                    goal_points = goal_points.checked_add(chain_bonus).ok_or(MomentumFiError::ArithmeticOverflow)?;
                 } 
                 // Add daily points only if at least 24 hours have passed AND it's past noon UTC
-                else if current_timestamp - goal_account.last_daily_reward_timestamp >= 86_400 && current_utc_time >= noon_utc {
-                    new_reward_points += ctx.accounts.config.daily_points;
+                else if goal_account.kind.pays_daily_points() && current_timestamp - goal_account.last_daily_reward_timestamp >= 86_400 && current_utc_time >= noon_utc {
+                    goal_account.daily_streak = next_streak(goal_account.daily_streak, goal_account.last_daily_reward_timestamp, current_timestamp, bridged_days);
+                    goal_points = daily_points_for_streak(&ctx.accounts.config, &goal_account)?;
                     goal_account.last_daily_reward_timestamp = current_timestamp; // Update last reward timestamp
+                    earned_daily_points = true;
                 }              
+            } else {
+                goal_account.daily_streak = 0; // Streak is lost once the goal is no longer met
+            }
+
+            let goal_points = apply_boost(goal_points, boost_bps)?; // Staking boost applies to completion and daily points
+
+            // Keep the lifetime total of the goal, claims only reset the user's claimable rewards
+            goal_account.total_points = goal_account.total_points.checked_add(goal_points).ok_or(MomentumFiError::ArithmeticOverflow)?;
+            if to_guardian {
+                guardian_points = guardian_points.checked_add(goal_points).ok_or(MomentumFiError::ArithmeticOverflow)?;
+            } else {
+                new_reward_points = new_reward_points.checked_add(goal_points).ok_or(MomentumFiError::ArithmeticOverflow)?;
             }
 
             // When serializing back, manually prepend the discriminator
@@ -83,8 +172,32 @@ impl<'info> UpdateRewardPointsAdmin<'info> {
             goal_account_data[8..8+serialized_data.len()].copy_from_slice(&serialized_data);
         }
 
+        // Count a new day in the user streak when any goal earned daily points
+        if earned_daily_points && current_timestamp - user_account.last_streak_timestamp >= 86_400 {
+            // Freezes are only consumed when the streak actually continues
+            if bridged_days > 0 {
+                user_account.streak_freezes -= bridged_days;
+                msg!("Used {} streak freezes to keep the streak", bridged_days);
+            }
+            user_account.daily_streak = next_streak(user_account.daily_streak, user_account.last_streak_timestamp, current_timestamp, bridged_days);
+            user_account.last_streak_timestamp = current_timestamp;
+            user_account.longest_streak = user_account.longest_streak.max(user_account.daily_streak);
+        }
+
         // Update user rewards
-        user_account.claimable_rewards += new_reward_points as u64;
+        award_points(user_account, new_reward_points)?;
+        award_guardian_points(ctx.accounts.guardian_account.as_deref_mut(), ctx.accounts.guardian_link.as_deref(), guardian_points)?;
+        if let Some(season) = ctx.accounts.season.as_deref_mut() {
+            record_season_points(user_account, season, new_reward_points, current_timestamp)?;
+        }
+        pay_referral_bonus(user_account, ctx.accounts.referrer_account.as_deref_mut(), &ctx.accounts.config)?;
+
+        if let Some(leaderboard) = ctx.accounts.leaderboard.as_deref_mut() {
+            update_leaderboard(leaderboard, user_account.owner, user_account.total_points);
+            if let Some(referrer_account) = ctx.accounts.referrer_account.as_deref() {
+                update_leaderboard(leaderboard, referrer_account.owner, referrer_account.total_points);
+            }
+        }
 
         Ok(())
     }
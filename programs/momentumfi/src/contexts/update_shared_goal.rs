@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, SharedGoal, UserAccount};
+use crate::utils::{award_points, lamports_to_usd, update_sol_price};
+
+// Permissionless, the user accounts of all members are passed as remaining accounts in member order
+#[derive(Accounts)]
+pub struct UpdateSharedGoal<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"shared_goal", shared_goal.creator.as_ref(), &shared_goal.goal_number.to_le_bytes()],
+        bump = shared_goal.bump,
+    )]
+    pub shared_goal: Account<'info, SharedGoal>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub price_update: Account<'info, PriceUpdateV2>,
+}
+
+impl<'info> UpdateSharedGoal<'info> {
+    pub fn update_shared_goal(ctx: Context<UpdateSharedGoal>) -> Result<()> {
+        require!(Clock::get()?.unix_timestamp <= ctx.accounts.shared_goal.deadline, MomentumFiError::SharedGoalExpired);
+        update_sol_price(&mut ctx.accounts.config, &ctx.accounts.price_update)?;
+
+        let shared_goal = &mut ctx.accounts.shared_goal;
+        let total_contributions = shared_goal.total_contributions();
+        let usd_balance = lamports_to_usd(total_contributions, ctx.accounts.config.sol_price);
+        // Nothing to split without contributions, the shares below divide by the total
+        shared_goal.completed = total_contributions > 0 && usd_balance >= shared_goal.target_usd;
+
+        if !shared_goal.completed || shared_goal.first_completed_bonus {
+            msg!("Shared goal USD balance is {}, target is {}", usd_balance, shared_goal.target_usd);
+            return Ok(());
+        }
+
+        let member_count = shared_goal.member_count as usize;
+        require!(ctx.remaining_accounts.len() == member_count, MomentumFiError::InvalidSharedGoalMembers);
+
+        // Split the completion bonus proportionally to the contributions
+        let points = ctx.accounts.config.first_completed_points as u64;
+        let mut paid_points: u64 = 0;
+        for (index, account_info) in ctx.remaining_accounts.iter().enumerate() {
+            require!(account_info.owner == ctx.program_id, MomentumFiError::InvalidSharedGoalMembers);
+
+            let mut user_account_data = account_info.data.borrow_mut();
+            require!(user_account_data[0..8] == UserAccount::DISCRIMINATOR, MomentumFiError::InvalidSharedGoalMembers);
+
+            let mut user_account = UserAccount::try_from_slice(&user_account_data[8..])?;
+            require!(user_account.owner == shared_goal.members[index], MomentumFiError::InvalidSharedGoalMembers);
+
+            let share = (points as u128 * shared_goal.contributions[index] as u128 / total_contributions as u128) as u64;
+            award_points(&mut user_account, share)?;
+            paid_points = paid_points.checked_add(share).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+            let serialized_data = user_account.try_to_vec()?;
+            user_account_data[8..8+serialized_data.len()].copy_from_slice(&serialized_data);
+        }
+
+        shared_goal.first_completed_bonus = true;
+        shared_goal.total_points = shared_goal.total_points.checked_add(paid_points).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+        msg!("Shared goal completed, {} points split between {} members", paid_points, member_count);
+
+        Ok(())
+    }
+}
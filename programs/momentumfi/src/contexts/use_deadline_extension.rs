@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::state::{GoalAccount, UserAccount};
+use crate::utils::SECONDS_PER_DAY;
+
+pub const DEADLINE_EXTENSION: i64 = 7 * SECONDS_PER_DAY; // Added to the deadline per extension
+
+#[derive(Accounts)]
+pub struct UseDeadlineExtension<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_account", user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        has_one = user @ MomentumFiError::UnauthorizedAccess,
+        seeds = [b"goal_account", user_account.key().as_ref(), &goal_account.goal_number.to_le_bytes()],
+        bump = goal_account.bump,
+    )]
+    pub goal_account: Account<'info, GoalAccount>,
+}
+
+impl<'info> UseDeadlineExtension<'info> {
+    pub fn use_deadline_extension(&mut self) -> Result<()> {
+        let goal_account = &mut self.goal_account;
+
+        // Failed goals can't be rescued, challenge entries share one deadline and guardians set their own
+        require!(
+            !goal_account.first_completed_bonus
+                && goal_account.deadline > Clock::get()?.unix_timestamp
+                && goal_account.challenge == Pubkey::default()
+                && goal_account.guardian == Pubkey::default(),
+            MomentumFiError::DeadlineExtensionUnavailable
+        );
+
+        self.user_account.deadline_extensions = self.user_account.deadline_extensions
+            .checked_sub(1)
+            .ok_or(MomentumFiError::NoDeadlineExtension)?;
+        goal_account.deadline = goal_account.deadline.checked_add(DEADLINE_EXTENSION).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+        msg!("Goal {} deadline extended to {}", goal_account.goal_number, goal_account.deadline);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::MomentumFiError;
+use crate::events::VaultWithdrawn;
+use crate::state::{GoalAccount, UserAccount};
+
+#[derive(Accounts)]
+pub struct WithdrawFromVault<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"user_account", user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        has_one = user @ MomentumFiError::UnauthorizedAccess,
+        seeds = [b"goal_account", user_account.key().as_ref(), &goal_account.goal_number.to_le_bytes()],
+        bump = goal_account.bump,
+    )]
+    pub goal_account: Account<'info, GoalAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"goal_vault", goal_account.key().as_ref()],
+        bump,
+    )]
+    pub goal_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawFromVault<'info> {
+    // Empties the vault, the savings stay locked until the goal is met or the deadline passed
+    pub fn withdraw_from_vault(&mut self, bumps: &WithdrawFromVaultBumps) -> Result<()> {
+        let goal_completed = self.goal_account.first_completed_bonus;
+        require!(
+            goal_completed || Clock::get()?.unix_timestamp > self.goal_account.deadline,
+            MomentumFiError::VaultLocked
+        );
+
+        let amount = self.goal_vault.lamports();
+        require!(amount > 0, MomentumFiError::InvalidVaultAmount);
+
+        let goal_key = self.goal_account.key();
+        let seeds = &[
+            &b"goal_vault"[..],
+            goal_key.as_ref(),
+            &[bumps.goal_vault],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.goal_vault.to_account_info(),
+                    to: self.user.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+        self.goal_account.vault_lamports = 0;
+
+        emit!(VaultWithdrawn {
+            user: self.user.key(),
+            goal_account: goal_key,
+            amount,
+            goal_completed,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MomentumFiError;
+use crate::events::SharedContributionWithdrawn;
+use crate::state::SharedGoal;
+
+#[derive(Accounts)]
+pub struct WithdrawSharedContribution<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"shared_goal", shared_goal.creator.as_ref(), &shared_goal.goal_number.to_le_bytes()],
+        bump = shared_goal.bump,
+    )]
+    pub shared_goal: Account<'info, SharedGoal>,
+}
+
+impl<'info> WithdrawSharedContribution<'info> {
+    // Returns the member's own contribution once the goal is met or the deadline passed
+    pub fn withdraw_shared_contribution(&mut self) -> Result<()> {
+        let goal_completed = self.shared_goal.first_completed_bonus;
+        require!(
+            goal_completed || Clock::get()?.unix_timestamp > self.shared_goal.deadline,
+            MomentumFiError::VaultLocked
+        );
+
+        let index = self.shared_goal.member_index(&self.member.key()).ok_or(MomentumFiError::NotSharedGoalMember)?;
+        let amount = self.shared_goal.contributions[index];
+        require!(amount > 0, MomentumFiError::InvalidVaultAmount);
+        self.shared_goal.contributions[index] = 0;
+
+        // The shared goal is owned by this program, so lamports can be moved without a CPI
+        **self.shared_goal.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **self.member.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        emit!(SharedContributionWithdrawn {
+            member: self.member.key(),
+            shared_goal: self.shared_goal.key(),
+            amount,
+            goal_completed,
+        });
+
+        Ok(())
+    }
+}
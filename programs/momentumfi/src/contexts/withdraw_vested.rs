@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::MomentumFiError;
+use crate::state::{Config, VestingAccount};
+use crate::utils::vested_amount;
+
+#[derive(Accounts)]
+pub struct WithdrawVested<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = rewards_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_rewards_ata: InterfaceAccount<'info, TokenAccount>, // User's ATA for the reward tokens
+
+    #[account(
+        mut,
+        seeds = [b"vesting", user.key().as_ref()],
+        bump = vesting_account.bump,
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = rewards_mint,
+        associated_token::authority = vesting_account,
+        associated_token::token_program = token_program,
+    )]
+    pub vesting_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"rewards", config.key().as_ref()],
+        bump = config.rewards_bump,
+        mint::token_program = token_program,
+    )]
+    pub rewards_mint: InterfaceAccount<'info, Mint>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawVested<'info> {
+    pub fn withdraw_vested(&mut self) -> Result<()> {
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        let vesting_account = &mut self.vesting_account;
+
+        let unlocked = vested_amount(
+            vesting_account.schedule_amount,
+            vesting_account.start_timestamp,
+            vesting_account.cliff,
+            vesting_account.duration,
+            current_timestamp,
+        );
+        // Joining a schedule can move its start past the cliff again, what was withdrawn stays withdrawn
+        let amount = vesting_account.carry_amount
+            .checked_add(unlocked.saturating_sub(vesting_account.schedule_withdrawn))
+            .ok_or(MomentumFiError::ArithmeticOverflow)?;
+        require!(amount > 0, MomentumFiError::NothingToWithdraw);
+
+        vesting_account.carry_amount = 0;
+        vesting_account.schedule_withdrawn = vesting_account.schedule_withdrawn.max(unlocked);
+
+        let cpi_accounts = TransferChecked {
+            from: self.vesting_escrow.to_account_info(),
+            mint: self.rewards_mint.to_account_info(),
+            to: self.user_rewards_ata.to_account_info(),
+            authority: self.vesting_account.to_account_info(),
+        };
+
+        let user_key = self.user.key();
+        let seeds = &[
+            &b"vesting"[..],
+            user_key.as_ref(),
+            &[self.vesting_account.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_context = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+        transfer_checked(cpi_context, amount, self.rewards_mint.decimals)?;
+
+        msg!("Withdrew {} vested reward tokens", amount);
+
+        Ok(())
+    }
+}
@@ -26,4 +26,118 @@ pub enum MomentumFiError{
     AccountDeserializationFailed,
     #[msg("User has no goals")]
     NoGoalsExist,
+    #[msg("A price update account is required for this goal")]
+    PriceUpdateRequired,
+    #[msg("Growth goals need a growth percentage above zero")]
+    InvalidGrowthTarget,
+    #[msg("The deadline must be in the future")]
+    InvalidDeadline,
+    #[msg("Hold goals need a hold duration of at least one day")]
+    InvalidHoldDuration,
+    #[msg("Streak tiers must be sorted by days with multipliers between 1x and 5x")]
+    InvalidStreakMultiplier,
+    #[msg("Symbol too long")]
+    SymbolTooLong,
+    #[msg("URI too long")]
+    UriTooLong,
+    #[msg("Mint extensions require the Token-2022 program")]
+    InvalidTokenProgram,
+    #[msg("The points conversion rate must be above zero")]
+    InvalidConversionRate,
+    #[msg("Invalid emission schedule")]
+    InvalidEmissionSchedule,
+    #[msg("The emission budget is exhausted, try again in the next epoch")]
+    EmissionBudgetExhausted,
+    #[msg("Vesting is not available for a non-transferable rewards mint")]
+    VestingUnavailable,
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+    #[msg("Vesting is enabled, the vesting account and escrow are required")]
+    VestingAccountsRequired,
+    #[msg("There are no vested rewards to withdraw")]
+    NothingToWithdraw,
+    #[msg("Staking is not available for a non-transferable rewards mint")]
+    StakingUnavailable,
+    #[msg("Invalid stake amount or lock duration")]
+    InvalidStakeAmount,
+    #[msg("The stake is still locked")]
+    StakeLocked,
+    #[msg("An enabled perk needs a cost above zero")]
+    InvalidPerkCost,
+    #[msg("This perk is not available")]
+    PerkUnavailable,
+    #[msg("Users can't refer themselves")]
+    SelfReferral,
+    #[msg("The milestone for this achievement has not been reached")]
+    MilestoneNotReached,
+    #[msg("The season must end after it starts")]
+    InvalidSeasonSchedule,
+    #[msg("The current season has not ended yet")]
+    SeasonInProgress,
+    #[msg("Season rewards can only be claimed once the season ended")]
+    SeasonNotEnded,
+    #[msg("No unclaimed points in this season")]
+    NoSeasonPoints,
+    #[msg("SOL target must be greater than zero")]
+    InvalidSolTarget,
+    #[msg("Challenges need a USD or SOL target and an entry window ending before the deadline")]
+    InvalidChallenge,
+    #[msg("The challenge is not open for entries")]
+    ChallengeEntryClosed,
+    #[msg("The goal stake escrow account is required to stake SOL")]
+    GoalStakeRequired,
+    #[msg("The goal stake must cover the rent-exempt minimum")]
+    GoalStakeTooSmall,
+    #[msg("The goal still holds a SOL stake")]
+    GoalStakeLocked,
+    #[msg("The goal has no SOL stake")]
+    NoGoalStake,
+    #[msg("The stake can only be reclaimed when the goal was completed before the deadline")]
+    GoalNotCompletedInTime,
+    #[msg("The stake can only be forfeited once the goal failed")]
+    GoalStakeNotForfeitable,
+    #[msg("Vault amount must be greater than zero and an empty vault must be funded above the rent-exempt minimum")]
+    InvalidVaultAmount,
+    #[msg("The vault unlocks once the goal is completed or the deadline passed")]
+    VaultLocked,
+    #[msg("The goal vault still holds SOL")]
+    VaultNotEmpty,
+    #[msg("Shared goal members must be unique, at most 4 besides the creator, and passed in member order")]
+    InvalidSharedGoalMembers,
+    #[msg("Signer is not a member of the shared goal")]
+    NotSharedGoalMember,
+    #[msg("A wallet cannot be its own guardian")]
+    InvalidGuardian,
+    #[msg("Guardians can only create Target and TargetSol goals")]
+    InvalidGoalKind,
+    #[msg("The goal was already completed")]
+    GoalAlreadyCompleted,
+    #[msg("The guardian link is required to update guardian-managed goals")]
+    GuardianLinkRequired,
+    #[msg("The guardian's user account is required to receive the rewards")]
+    GuardianAccountRequired,
+    #[msg("Quests need 1 to 5 Target, TargetSol or HoldDuration steps and a step duration")]
+    InvalidQuest,
+    #[msg("The quest template is required to advance a completed quest step")]
+    QuestTemplateRequired,
+    #[msg("Goal templates need a duration and the parameters of their kind")]
+    InvalidGoalTemplate,
+    #[msg("Difficulty range must include 1x and stay at most 5x")]
+    InvalidDifficultyCurve,
+    #[msg("Goal account does not belong to this program or to the user being updated")]
+    InvalidGoalAccount,
+    #[msg("USD target must be greater than zero")]
+    InvalidUsdTarget,
+    #[msg("No free goal slot, delete a goal or redeem an extra goal slot")]
+    NoGoalSlot,
+    #[msg("No deadline extension left")]
+    NoDeadlineExtension,
+    #[msg("Only running goals outside of challenges can be extended")]
+    DeadlineExtensionUnavailable,
+    #[msg("Re-initializing can't change the extensions of the existing rewards mint")]
+    RewardsMintMismatch,
+    #[msg("The shared goal deadline has passed")]
+    SharedGoalExpired,
+    #[msg("Only accounts of the first deployment can be migrated")]
+    InvalidMigration,
 }
\ No newline at end of file
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct VaultDeposited {
+    pub user: Pubkey,
+    pub goal_account: Pubkey,
+    pub amount: u64,
+    pub vault_lamports: u64, // Vault balance after the deposit
+}
+
+#[event]
+pub struct VaultWithdrawn {
+    pub user: Pubkey,
+    pub goal_account: Pubkey,
+    pub amount: u64,
+    pub goal_completed: bool, // False when withdrawn because the deadline passed
+}
+
+#[event]
+pub struct SharedGoalContributed {
+    pub member: Pubkey,
+    pub shared_goal: Pubkey,
+    pub amount: u64,
+    pub total_contributions: u64, // Contributions of all members after this one
+}
+
+#[event]
+pub struct SharedContributionWithdrawn {
+    pub member: Pubkey,
+    pub shared_goal: Pubkey,
+    pub amount: u64,
+    pub goal_completed: bool, // False when withdrawn because the deadline passed
+}
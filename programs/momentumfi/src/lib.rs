@@ -5,30 +5,127 @@ declare_id!("9Z61ua1wnAiVaJHZLJuTeZoSfWn1X9Nb9d42bZjut7U8");
 pub mod contexts;
 pub mod state;
 pub mod errors;
+pub mod events;
 pub mod utils;
 
 extern crate chrono;
 use crate::contexts::*;
 use crate::errors::*;
+use crate::state::{GoalKind, Milestone, PerkKind, QuestStep, StreakMultiplier, STREAK_TIERS};
 
 #[program]
 pub mod momentumfi {
     use super::*;
 
-    pub fn initialize(ctx: Context<Initialize>, first_completed_points: u16, daily_points: u16) -> Result<()> {
-        ctx.accounts.init(first_completed_points, daily_points, &ctx.bumps)
+    pub fn initialize(ctx: Context<Initialize>, first_completed_points: u16, daily_points: u16, soulbound: bool, metadata_pointer: bool) -> Result<()> {
+        ctx.accounts.init(first_completed_points, daily_points, soulbound, metadata_pointer, &ctx.bumps)
     }
 
     pub fn register_user_account(ctx: Context<RegisterUserAccount>) -> Result<()> {
         ctx.accounts.register_user_account(&ctx.bumps)
     }
 
-    pub fn create_goal(ctx: Context<CreateGoal>, goal_number: u64, target_usd: u64, deadline: i64) -> Result<()> {
-        CreateGoal::create_goal(ctx, goal_number, target_usd, deadline)
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_goal(ctx: Context<CreateGoal>, goal_number: u64, target_usd: u64, deadline: i64, kind: GoalKind, growth_bps: u16, hold_days: u16, target_sol: u64, stake_lamports: u64) -> Result<()> {
+        CreateGoal::create_goal(ctx, goal_number, target_usd, deadline, kind, growth_bps, hold_days, target_sol, stake_lamports)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_challenge(
+        ctx: Context<CreateChallenge>,
+        kind: GoalKind,
+        target_usd: u64,
+        target_sol: u64,
+        entry_start: i64,
+        entry_end: i64,
+        deadline: i64,
+        reward_points: u64,
+    ) -> Result<()> {
+        ctx.accounts.create_challenge(kind, target_usd, target_sol, entry_start, entry_end, deadline, reward_points, &ctx.bumps)
+    }
+
+    pub fn join_challenge(ctx: Context<JoinChallenge>, goal_number: u64) -> Result<()> {
+        ctx.accounts.join_challenge(goal_number, &ctx.bumps)
+    }
+
+    pub fn reclaim_goal_stake(ctx: Context<ReclaimGoalStake>) -> Result<()> {
+        ctx.accounts.reclaim_goal_stake(&ctx.bumps)
+    }
+
+    pub fn forfeit_goal_stake(ctx: Context<ForfeitGoalStake>) -> Result<()> {
+        ctx.accounts.forfeit_goal_stake(&ctx.bumps)
+    }
+
+    pub fn deposit_to_vault(ctx: Context<DepositToVault>, amount: u64) -> Result<()> {
+        ctx.accounts.deposit_to_vault(amount)
+    }
+
+    pub fn withdraw_from_vault(ctx: Context<WithdrawFromVault>) -> Result<()> {
+        ctx.accounts.withdraw_from_vault(&ctx.bumps)
+    }
+
+    pub fn create_shared_goal(ctx: Context<CreateSharedGoal>, goal_number: u64, target_usd: u64, deadline: i64, members: Vec<Pubkey>) -> Result<()> {
+        ctx.accounts.create_shared_goal(goal_number, target_usd, deadline, members, &ctx.bumps)
+    }
+
+    pub fn contribute_shared_goal(ctx: Context<ContributeSharedGoal>, amount: u64) -> Result<()> {
+        ctx.accounts.contribute_shared_goal(amount)
+    }
+
+    pub fn update_shared_goal(ctx: Context<UpdateSharedGoal>) -> Result<()> {
+        UpdateSharedGoal::update_shared_goal(ctx)
+    }
+
+    pub fn withdraw_shared_contribution(ctx: Context<WithdrawSharedContribution>) -> Result<()> {
+        ctx.accounts.withdraw_shared_contribution()
+    }
+
+    pub fn link_guardian(ctx: Context<LinkGuardian>, rewards_to_guardian: bool) -> Result<()> {
+        ctx.accounts.link_guardian(rewards_to_guardian, &ctx.bumps)
+    }
+
+    pub fn guardian_create_goal(ctx: Context<GuardianCreateGoal>, goal_number: u64, kind: GoalKind, target_usd: u64, target_sol: u64, deadline: i64) -> Result<()> {
+        ctx.accounts.guardian_create_goal(goal_number, kind, target_usd, target_sol, deadline, &ctx.bumps)
+    }
+
+    pub fn guardian_update_goal(ctx: Context<GuardianManageGoal>, target_usd: u64, target_sol: u64, deadline: i64) -> Result<()> {
+        ctx.accounts.guardian_update_goal(target_usd, target_sol, deadline)
+    }
+
+    pub fn guardian_delete_goal(ctx: Context<GuardianManageGoal>) -> Result<()> {
+        ctx.accounts.guardian_delete_goal()
+    }
+
+    pub fn create_quest(ctx: Context<CreateQuest>, steps: Vec<QuestStep>, step_duration: i64, chain_bonus_points: u64) -> Result<()> {
+        ctx.accounts.create_quest(steps, step_duration, chain_bonus_points, &ctx.bumps)
+    }
+
+    pub fn start_quest(ctx: Context<StartQuest>, goal_number: u64) -> Result<()> {
+        ctx.accounts.start_quest(goal_number, &ctx.bumps)
+    }
+
+    pub fn create_goal_template(ctx: Context<CreateGoalTemplate>, template: GoalTemplateParams) -> Result<()> {
+        ctx.accounts.create_goal_template(template, &ctx.bumps)
+    }
+
+    pub fn create_goal_from_template(ctx: Context<CreateGoalFromTemplate>, goal_number: u64) -> Result<()> {
+        ctx.accounts.create_goal_from_template(goal_number, &ctx.bumps)
+    }
+
+    pub fn set_difficulty_curve(ctx: Context<UpdateConfig>, gap_weight_bps: u16, reference_days: u16, min_difficulty_bps: u16, max_difficulty_bps: u16) -> Result<()> {
+        ctx.accounts.set_difficulty_curve(gap_weight_bps, reference_days, min_difficulty_bps, max_difficulty_bps)
+    }
+
+    pub fn set_goal_slots(ctx: Context<UpdateConfig>, goal_slots: u16) -> Result<()> {
+        ctx.accounts.set_goal_slots(goal_slots)
+    }
+
+    pub fn set_treasury(ctx: Context<UpdateConfig>, treasury: Pubkey) -> Result<()> {
+        ctx.accounts.set_treasury(treasury)
     }
 
     pub fn delete_goal(ctx: Context<DeleteGoal>) -> Result<()> {
-        DeleteGoal::delete_goal()
+        DeleteGoal::delete_goal(ctx)
     }
 
     pub fn update_reward_points_admin(ctx: Context<UpdateRewardPointsAdmin>) -> Result<()> {
@@ -42,5 +139,93 @@ pub mod momentumfi {
     pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
         ClaimRewards::claim_rewards(ctx)
     }
+
+    pub fn withdraw_vested(ctx: Context<WithdrawVested>) -> Result<()> {
+        ctx.accounts.withdraw_vested()
+    }
+
+    pub fn stake_rewards(ctx: Context<StakeRewards>, amount: u64, lock_duration: i64) -> Result<()> {
+        ctx.accounts.stake_rewards(amount, lock_duration, &ctx.bumps)
+    }
+
+    pub fn unstake_rewards(ctx: Context<UnstakeRewards>) -> Result<()> {
+        ctx.accounts.unstake_rewards()
+    }
+
+    pub fn set_perk(ctx: Context<SetPerk>, kind: PerkKind, cost: u64, enabled: bool) -> Result<()> {
+        ctx.accounts.set_perk(kind, cost, enabled, &ctx.bumps)
+    }
+
+    pub fn redeem_perk(ctx: Context<RedeemPerk>, kind: PerkKind) -> Result<()> {
+        ctx.accounts.redeem_perk(kind)
+    }
+
+    pub fn use_deadline_extension(ctx: Context<UseDeadlineExtension>) -> Result<()> {
+        ctx.accounts.use_deadline_extension()
+    }
+
+    pub fn grant_streak_freezes(ctx: Context<GrantStreakFreezes>, amount: u16) -> Result<()> {
+        ctx.accounts.grant_streak_freezes(amount)
+    }
+
+    pub fn init_leaderboard(ctx: Context<InitLeaderboard>) -> Result<()> {
+        ctx.accounts.init_leaderboard(&ctx.bumps)
+    }
+
+    pub fn start_season(ctx: Context<StartSeason>, start_timestamp: i64, end_timestamp: i64, reward_pool: u64) -> Result<()> {
+        ctx.accounts.start_season(start_timestamp, end_timestamp, reward_pool, &ctx.bumps)
+    }
+
+    pub fn claim_season_reward(ctx: Context<ClaimSeasonReward>, season_number: u64) -> Result<()> {
+        ctx.accounts.claim_season_reward(season_number)
+    }
+
+    pub fn mint_achievement(ctx: Context<MintAchievement>, milestone: Milestone) -> Result<()> {
+        ctx.accounts.mint_achievement(milestone, &ctx.bumps)
+    }
+
+    pub fn set_streak_multipliers(ctx: Context<UpdateConfig>, streak_multipliers: [StreakMultiplier; STREAK_TIERS]) -> Result<()> {
+        ctx.accounts.set_streak_multipliers(streak_multipliers)
+    }
+
+    pub fn set_points_conversion_rate(ctx: Context<UpdateConfig>, points_conversion_rate: u64) -> Result<()> {
+        ctx.accounts.set_points_conversion_rate(points_conversion_rate)
+    }
+
+    pub fn set_emission_schedule(ctx: Context<UpdateConfig>, max_supply: u64, epoch_duration: i64, epoch_emission_budget: u64, halving_interval: u16) -> Result<()> {
+        ctx.accounts.set_emission_schedule(max_supply, epoch_duration, epoch_emission_budget, halving_interval)
+    }
+
+    pub fn set_vesting(ctx: Context<UpdateConfig>, vesting_enabled: bool, vesting_cliff: i64, vesting_duration: i64) -> Result<()> {
+        ctx.accounts.set_vesting(vesting_enabled, vesting_cliff, vesting_duration)
+    }
+
+    pub fn set_stake_boost(ctx: Context<UpdateConfig>, stake_boost_unit: u64, stake_boost_bps_per_unit: u16, lock_boost_bps_per_day: u16, max_stake_boost_bps: u16) -> Result<()> {
+        ctx.accounts.set_stake_boost(stake_boost_unit, stake_boost_bps_per_unit, lock_boost_bps_per_day, max_stake_boost_bps)
+    }
+
+    pub fn set_referral_bonus(ctx: Context<UpdateConfig>, referral_bonus_points: u64) -> Result<()> {
+        ctx.accounts.set_referral_bonus(referral_bonus_points)
+    }
+
+    pub fn set_achievement_base_uri(ctx: Context<UpdateConfig>, achievement_base_uri: String) -> Result<()> {
+        ctx.accounts.set_achievement_base_uri(achievement_base_uri)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        ctx.accounts.migrate_config()
+    }
+
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        ctx.accounts.migrate_account()
+    }
+
+    pub fn create_rewards_metadata(ctx: Context<RewardsMetadata>, name: String, symbol: String, uri: String) -> Result<()> {
+        ctx.accounts.create_rewards_metadata(name, symbol, uri)
+    }
+
+    pub fn update_rewards_metadata(ctx: Context<RewardsMetadata>, name: String, symbol: String, uri: String) -> Result<()> {
+        ctx.accounts.update_rewards_metadata(name, symbol, uri)
+    }
     
 }
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Milestone {
+    FirstGoal, // First completed goal
+    TenGoals, // Ten completed goals
+    ThirtyDayStreak, // 30 day daily streak
+}
+
+impl Milestone {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Milestone::FirstGoal => "MomentumFi First Goal",
+            Milestone::TenGoals => "MomentumFi 10 Goals",
+            Milestone::ThirtyDayStreak => "MomentumFi 30 Day Streak",
+        }
+    }
+
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Milestone::FirstGoal => "first-goal",
+            Milestone::TenGoals => "ten-goals",
+            Milestone::ThirtyDayStreak => "thirty-day-streak",
+        }
+    }
+}
+
+#[account]
+#[derive(Debug)]
+pub struct AchievementRecord {
+    pub owner: Pubkey,
+    pub milestone: Milestone,
+    pub mint: Pubkey, // Mint of the achievement NFT
+    pub timestamp: i64, // When the achievement was minted
+    pub bump: u8,
+}
+
+impl Space for AchievementRecord {
+    const INIT_SPACE: usize = 32 + 1 + 32 + 8 + 1;
+}
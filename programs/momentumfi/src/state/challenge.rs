@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::GoalKind;
+
+#[account]
+#[derive(Debug)]
+pub struct Challenge {
+    pub challenge_id: u64,
+    pub kind: GoalKind, // Target or TargetSol
+    pub target_usd: u64, // Target USD value (Target challenges only)
+    pub target_sol: u64, // Target SOL balance in lamports (TargetSol challenges only)
+    pub entry_start: i64, // Users can join from this time on
+    pub entry_end: i64, // Users can no longer join after this time
+    pub deadline: i64, // Deadline of the goals created for the challenge
+    pub reward_points: u64, // Extra points paid when a participant completes the challenge
+    pub participants: u64,
+    pub bump: u8,
+}
+
+impl Space for Challenge {
+    const INIT_SPACE: usize = 8 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+}
+
+#[account]
+#[derive(Debug)]
+pub struct ChallengeEntry {
+    pub challenge: Pubkey,
+    pub user: Pubkey,
+    pub goal_number: u64, // Goal created for the user when joining
+    pub bump: u8,
+}
+
+impl Space for ChallengeEntry {
+    const INIT_SPACE: usize = 32 + 32 + 8 + 1;
+}
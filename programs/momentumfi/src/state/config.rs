@@ -1,6 +1,16 @@
 use anchor_lang::prelude::*;
 
+pub const STREAK_TIERS: usize = 4;
+pub const MAX_ACHIEVEMENT_URI_LENGTH: usize = 160;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct StreakMultiplier {
+    pub min_days: u16, // Streak length (in days) from which this tier applies, 0 disables the tier
+    pub multiplier_bps: u16, // Multiplier applied to daily_points in basis points (15000 -> 1.5x)
+}
+
 #[account]
+#[derive(Default)]
 pub struct Config {
     pub authority: Pubkey, // The authority that is allowed to edit the config account
     pub daily_points: u16, // points a user gets when they are above a goal every day
@@ -9,8 +19,39 @@ pub struct Config {
     pub price_last_updated: i64, // Timestamp of last update
     pub rewards_bump: u8, // Bump seed for the rewards mint account
     pub config_bump: u8, // Bump seed for the config accounts
+    pub streak_multipliers: [StreakMultiplier; STREAK_TIERS], // Daily points multipliers by streak length, sorted by min_days
+    pub rewards_token_program: Pubkey, // Token program that owns the rewards mint (Token or Token-2022)
+    pub soulbound: bool, // Whether the rewards mint is non-transferable
+    pub points_conversion_rate: u64, // Rewards mint base units minted per claimed point
+    pub max_supply: u64, // Hard cap on base units minted by the program, 0 if uncapped
+    pub total_minted: u64, // Base units minted by the program so far
+    pub emissions_start: i64, // Start of the first emission epoch
+    pub epoch_duration: i64, // Length of an emission epoch in seconds, 0 disables the per-epoch budget
+    pub epoch_emission_budget: u64, // Base units that can be minted in the first epoch
+    pub halving_interval: u16, // Number of epochs after which the budget halves, 0 disables halving
+    pub current_epoch: u64, // Index of the current emission epoch
+    pub epoch_minted: u64, // Base units minted in the current epoch
+    pub vesting_enabled: bool, // Whether claims are minted into the user's vesting escrow
+    pub vesting_cliff: i64, // Seconds after a claim before vested rewards start unlocking
+    pub vesting_duration: i64, // Seconds after a claim until vested rewards are fully unlocked
+    pub stake_boost_unit: u64, // Staked base units per boost step, 0 disables the amount boost
+    pub stake_boost_bps_per_unit: u16, // Boost in basis points for each stake_boost_unit staked
+    pub lock_boost_bps_per_day: u16, // Boost in basis points for each day of lock duration
+    pub max_stake_boost_bps: u16, // Cap on the total staking boost in basis points
+    pub referral_bonus_points: u64, // Points credited to the referrer when a referred user first completes a goal
+    pub current_season: u64, // Latest scheduled season, 0 before the first season
+    pub challenge_count: u64, // Used to ensure each challenge gets a unique PDA seed
+    pub treasury: Pubkey, // Receives forfeited goal stakes
+    pub quest_count: u64, // Used to ensure each quest template gets a unique PDA seed
+    pub goal_template_count: u64, // Used to ensure each goal template gets a unique PDA seed
+    pub difficulty_gap_weight_bps: u16, // Share of the relative gap between baseline and target added to the completion bonus
+    pub difficulty_reference_days: u16, // Goals shorter than this scale their difficulty up, 0 ignores the time to deadline
+    pub min_difficulty_bps: u16, // Multiplier for goals whose target is not above the baseline
+    pub max_difficulty_bps: u16, // Cap of the difficulty multiplier, 0 disables difficulty scaling
+    pub goal_slots: u16, // Open goals per user before extra goal slot perks, 0 disables the limit
+    pub achievement_base_uri: String, // Base of the achievement NFT metadata URIs, the milestone slug is appended
 }
 
 impl Space for Config {
-    const INIT_SPACE: usize =  32 + 2 + 2 + 8 + 8 + 1 + 1;
+    const INIT_SPACE: usize =  32 + 2 + 2 + 8 + 8 + 1 + 1 + (2 + 2) * STREAK_TIERS + 32 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 2 + 8 + 8 + 1 + 8 + 8 + 8 + 2 + 2 + 2 + 8 + 8 + 8 + 32 + 8 + 8 + 2 + 2 + 2 + 2 + 2 + 4 + MAX_ACHIEVEMENT_URI_LENGTH;
 }
\ No newline at end of file
@@ -1,7 +1,24 @@
 use anchor_lang::prelude::*;
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GoalKind {
+    #[default]
+    Target, // Reach a fixed target_usd
+    Growth, // Grow the baseline USD balance by growth_bps
+    StayAbove, // Keep the USD balance above target_usd (the floor) until the deadline
+    HoldDuration, // Hold the USD balance above target_usd for hold_days consecutive days
+    TargetSol, // Reach a fixed target_sol
+}
+
+impl GoalKind {
+    // Floor and hold goals only pay once, at the deadline or at the end of the streak
+    pub fn pays_daily_points(&self) -> bool {
+        !matches!(self, GoalKind::StayAbove | GoalKind::HoldDuration)
+    }
+}
+
 #[account]
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct GoalAccount {
     pub user: Pubkey,
     pub total_points: u64,  // total points for this goal account
@@ -12,8 +29,31 @@ pub struct GoalAccount {
     pub goal_number: u64,
     pub completed: bool,    // Whether the goal is completed
     pub first_completed_bonus: bool,
+    pub kind: GoalKind,
+    pub growth_bps: u16,    // Growth over the baseline in basis points (Growth goals only)
+    pub baseline_usd: u64,  // User's USD balance snapshot at creation
+    pub baseline_sol: u64,  // User's SOL balance (in lamports) snapshot at creation
+    pub breached: bool,     // Whether the floor of a StayAbove goal was breached
+    pub breach_timestamp: i64, // Timestamp of the first observed breach
+    pub breach_usd_balance: u64, // USD balance observed at the first breach
+    pub hold_days: u16,     // Consecutive days required by a HoldDuration goal
+    pub streak_start_timestamp: i64, // Start of the current streak above target_usd (0 if none)
+    pub streak_days: u16,   // Length of the current streak in days
+    pub daily_streak: u16,  // Consecutive days this goal earned daily points
+    pub target_sol: u64,    // Target SOL balance in lamports (TargetSol goals only)
+    pub challenge: Pubkey,  // Challenge this goal was created for, default if none
+    pub challenge_reward_points: u64, // Extra points paid on completion of a challenge goal
+    pub stake_lamports: u64, // SOL locked in the goal stake escrow, returned on completion or forfeited
+    pub completed_timestamp: i64, // Time of the first completion (0 if never completed)
+    pub vault_lamports: u64, // SOL saved in the goal vault, counts towards the goal's balance
+    pub guardian: Pubkey,   // Guardian who created and manages this goal, default if none
+    pub quest: Pubkey,      // Quest template this goal advances through, default if none
+    pub quest_step: u8,     // Current step of the quest
+    pub first_completed_points_override: u64, // Replaces config.first_completed_points when not 0
+    pub daily_points_override: u64, // Replaces config.daily_points when not 0
+    pub last_hold_observation: i64, // Last update that saw the balance above target_usd during the streak (0 if none)
     pub bump: u8,
-    pub _padding: [u8; 5],  // 5 bytes to ensure 8-byte alignment
+    pub _padding: [u8; 2],  // 2 bytes to ensure 8-byte alignment, recompute when adding fields
 }
 
 impl Space for GoalAccount {
@@ -27,6 +67,29 @@ impl Space for GoalAccount {
         + 8    // goal_number: u64
         + 1    // completed: bool
         + 1    // first_completed_bonus: bool
+        + 1    // kind: GoalKind
+        + 2    // growth_bps: u16
+        + 8    // baseline_usd: u64
+        + 8    // baseline_sol: u64
+        + 1    // breached: bool
+        + 8    // breach_timestamp: i64
+        + 8    // breach_usd_balance: u64
+        + 2    // hold_days: u16
+        + 8    // streak_start_timestamp: i64
+        + 2    // streak_days: u16
+        + 2    // daily_streak: u16
+        + 8    // target_sol: u64
+        + 32   // challenge: Pubkey
+        + 8    // challenge_reward_points: u64
+        + 8    // stake_lamports: u64
+        + 8    // completed_timestamp: i64
+        + 8    // vault_lamports: u64
+        + 32   // guardian: Pubkey
+        + 32   // quest: Pubkey
+        + 1    // quest_step: u8
+        + 8    // first_completed_points_override: u64
+        + 8    // daily_points_override: u64
+        + 8    // last_hold_observation: i64
         + 1    // bump: u8
-        + 5;   // _padding: [u8; 5]
+        + 2;   // _padding: [u8; 2]
 }
\ No newline at end of file
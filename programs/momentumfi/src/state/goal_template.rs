@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::GoalKind;
+
+pub const MAX_TEMPLATE_NAME_LENGTH: usize = 32;
+
+#[account]
+#[derive(Debug)]
+pub struct GoalTemplate {
+    pub template_id: u64,
+    pub name: String, // Shown to users when picking a template
+    pub kind: GoalKind,
+    pub target_usd: u64, // Suggested target, used as is
+    pub target_sol: u64, // In lamports (TargetSol templates only)
+    pub growth_bps: u16, // Growth templates only
+    pub hold_days: u16, // HoldDuration templates only
+    pub duration: i64, // Seconds from creation until the deadline
+    pub first_completed_points_override: u64, // Replaces config.first_completed_points when not 0
+    pub daily_points_override: u64, // Replaces config.daily_points when not 0
+    pub bump: u8,
+}
+
+impl Space for GoalTemplate {
+    const INIT_SPACE: usize = 8 + 4 + MAX_TEMPLATE_NAME_LENGTH + 1 + 8 + 8 + 2 + 2 + 8 + 8 + 8 + 1;
+}
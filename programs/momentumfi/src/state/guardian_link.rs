@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Debug)]
+pub struct GuardianLink {
+    pub guardian: Pubkey, // Wallet allowed to manage goals of the dependent
+    pub dependent: Pubkey, // Wallet of the dependent user account
+    pub rewards_to_guardian: bool, // Whether points of guardian-managed goals accrue to the guardian
+    pub bump: u8,
+}
+
+impl Space for GuardianLink {
+    const INIT_SPACE: usize = 32 + 32 + 1 + 1;
+}
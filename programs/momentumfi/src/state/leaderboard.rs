@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+pub const LEADERBOARD_SIZE: usize = 10;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct LeaderboardEntry {
+    pub user: Pubkey, // Owner of the user account, default when the slot is empty
+    pub points: u64, // Lifetime total_points of the user
+}
+
+#[account]
+#[derive(Debug)]
+pub struct Leaderboard {
+    pub entries: [LeaderboardEntry; LEADERBOARD_SIZE], // Sorted by points, highest first
+    pub bump: u8,
+}
+
+impl Space for Leaderboard {
+    const INIT_SPACE: usize = (32 + 8) * LEADERBOARD_SIZE + 1;
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+// Layouts written by the first deployment, only read to migrate those accounts to the current layouts.
+// The discriminators are the same, the accounts are told apart by their size.
+
+#[derive(AnchorDeserialize, Debug)]
+pub struct LegacyConfig {
+    pub authority: Pubkey,
+    pub daily_points: u16,
+    pub first_completed_points: u16,
+    pub sol_price: u64,
+    pub price_last_updated: i64,
+    pub rewards_bump: u8,
+    pub config_bump: u8,
+}
+
+impl Space for LegacyConfig {
+    const INIT_SPACE: usize = 32 + 2 + 2 + 8 + 8 + 1 + 1;
+}
+
+#[derive(AnchorDeserialize, Debug)]
+pub struct LegacyUserAccount {
+    pub owner: Pubkey,
+    pub total_points: u64,
+    pub claimable_rewards: u64,
+    pub sol_balance: u64,
+    pub usd_balance: u64,
+    pub goal_count: u64,
+    pub bump: u8,
+}
+
+impl Space for LegacyUserAccount {
+    const INIT_SPACE: usize = 32 + 8 + 8 + 8 + 8 + 8 + 1;
+}
+
+#[derive(AnchorDeserialize, Debug)]
+pub struct LegacyGoalAccount {
+    pub user: Pubkey,
+    pub total_points: u64,
+    pub creation_timestamp: i64,
+    pub target_usd: u64,
+    pub deadline: i64,
+    pub last_daily_reward_timestamp: i64,
+    pub goal_number: u64,
+    pub completed: bool,
+    pub first_completed_bonus: bool,
+    pub bump: u8,
+    pub _padding: [u8; 5],
+}
+
+impl Space for LegacyGoalAccount {
+    const INIT_SPACE: usize = 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + 5;
+}
@@ -5,4 +5,40 @@ pub use user_account::*;
 pub mod user_account;
 
 pub use goal_account::*;
-pub mod goal_account;
\ No newline at end of file
+pub mod goal_account;
+
+pub use legacy_accounts::*;
+pub mod legacy_accounts;
+
+pub use vesting_account::*;
+pub mod vesting_account;
+
+pub use stake_account::*;
+pub mod stake_account;
+
+pub use perk_catalog::*;
+pub mod perk_catalog;
+
+pub use achievement_record::*;
+pub mod achievement_record;
+
+pub use leaderboard::*;
+pub mod leaderboard;
+
+pub use season::*;
+pub mod season;
+
+pub use challenge::*;
+pub mod challenge;
+
+pub use shared_goal::*;
+pub mod shared_goal;
+
+pub use guardian_link::*;
+pub mod guardian_link;
+
+pub use quest_template::*;
+pub mod quest_template;
+
+pub use goal_template::*;
+pub mod goal_template;
\ No newline at end of file
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+pub const PERK_KINDS: usize = 3;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PerkKind {
+    ExtraGoalSlot,
+    StreakFreeze,
+    DeadlineExtension,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct Perk {
+    pub cost: u64, // Base units of reward tokens burned to redeem the perk
+    pub enabled: bool,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct PerkCatalog {
+    pub perks: [Perk; PERK_KINDS], // Indexed by PerkKind
+    pub bump: u8,
+}
+
+impl Space for PerkCatalog {
+    const INIT_SPACE: usize = (8 + 1) * PERK_KINDS + 1;
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::state::GoalKind;
+
+pub const MAX_QUEST_STEPS: usize = 5;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct QuestStep {
+    pub kind: GoalKind, // Target, TargetSol or HoldDuration
+    pub target_usd: u64,
+    pub target_sol: u64, // In lamports (TargetSol steps only)
+    pub hold_days: u16, // HoldDuration steps only
+}
+
+#[account]
+#[derive(Debug)]
+pub struct QuestTemplate {
+    pub quest_id: u64,
+    pub steps: [QuestStep; MAX_QUEST_STEPS], // Completed in order, unused slots are default
+    pub step_count: u8,
+    pub step_duration: i64, // Seconds each step has from being unlocked until its deadline
+    pub chain_bonus_points: u64, // Paid when the last step completes
+    pub bump: u8,
+}
+
+impl Space for QuestTemplate {
+    const INIT_SPACE: usize = 8 + (1 + 8 + 8 + 2) * MAX_QUEST_STEPS + 1 + 8 + 8 + 1;
+}
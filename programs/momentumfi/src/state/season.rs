@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Debug)]
+pub struct Season {
+    pub season_number: u64, // Starts at 1, matches config.current_season while running
+    pub start_timestamp: i64,
+    pub end_timestamp: i64, // Points earned from this time on no longer count for the season
+    pub reward_pool: u64, // Base units of reward tokens split proportionally to season points
+    pub total_points: u64, // Season points earned by all users
+    pub distributed: u64, // Base units of the pool already claimed
+    pub bump: u8,
+}
+
+impl Space for Season {
+    const INIT_SPACE: usize = 8 + 8 + 8 + 8 + 8 + 8 + 1;
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_SHARED_GOAL_MEMBERS: usize = 5;
+
+#[account]
+#[derive(Debug)]
+pub struct SharedGoal {
+    pub creator: Pubkey,
+    pub goal_number: u64, // Chosen by the creator, used as PDA seed
+    pub members: [Pubkey; MAX_SHARED_GOAL_MEMBERS], // Creator first, unused slots are default
+    pub member_count: u8,
+    pub contributions: [u64; MAX_SHARED_GOAL_MEMBERS], // Lamports deposited by each member, held by this account
+    pub target_usd: u64, // Target USD value of all contributions together
+    pub deadline: i64,
+    pub creation_timestamp: i64,
+    pub total_points: u64, // Points paid out to the members
+    pub completed: bool,
+    pub first_completed_bonus: bool,
+    pub bump: u8,
+}
+
+impl Space for SharedGoal {
+    const INIT_SPACE: usize = 32 + 8 + 32 * MAX_SHARED_GOAL_MEMBERS + 1 + 8 * MAX_SHARED_GOAL_MEMBERS + 8 + 8 + 8 + 8 + 1 + 1 + 1;
+}
+
+impl SharedGoal {
+    pub fn member_index(&self, member: &Pubkey) -> Option<usize> {
+        self.members[..self.member_count as usize].iter().position(|m| m == member)
+    }
+
+    pub fn total_contributions(&self) -> u64 {
+        self.contributions.iter().sum()
+    }
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Debug)]
+pub struct StakeAccount {
+    pub owner: Pubkey,
+    pub amount: u64, // Base units of reward tokens locked in the stake vault
+    pub lock_start: i64, // Timestamp of the last stake
+    pub lock_until: i64, // Tokens can't be unstaked before this timestamp
+    pub lock_duration: i64, // Length of the current lock in seconds, used for the boost
+    pub bump: u8,
+}
+
+impl Space for StakeAccount {
+    const INIT_SPACE: usize = 32 + 8 + 8 + 8 + 8 + 1;
+}
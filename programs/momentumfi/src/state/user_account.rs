@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 #[account]
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct UserAccount {
     pub owner: Pubkey,
     pub total_points: u64, // total points for this user account
@@ -9,9 +9,24 @@ pub struct UserAccount {
     pub sol_balance: u64, // Amount of SOL (in lamports) in the related user account wallet
     pub usd_balance: u64,  // Cached USD value of user's SOL balance
     pub goal_count: u64,  // Used to ensure each goal account gets a unuque PDA seed
+    pub daily_streak: u16, // Consecutive days in which at least one goal earned daily points
+    pub longest_streak: u16, // Longest daily_streak ever reached
+    pub last_streak_timestamp: i64, // Timestamp of the last day counted in daily_streak
+    pub extra_goal_slots: u16, // Redeemed extra goal slot perks
+    pub streak_freezes: u16, // Redeemed streak freeze perks
+    pub deadline_extensions: u16, // Redeemed deadline extension perks
+    pub completed_goals: u64, // Number of goals completed for the first time
+    pub referrer: Pubkey, // Wallet of the user who invited this user, default if none
+    pub referral_bonus_paid: bool, // Whether the referrer received the bonus for this user
+    pub season_number: u64, // Season the season_points were earned in
+    pub season_points: u64, // Unclaimed points earned during season_number
+    pub last_season_number: u64, // Previous season with unclaimed points, kept until claimed
+    pub last_season_points: u64,
+    pub open_goals: u16, // Goals not deleted yet, limited by config.goal_slots plus extra_goal_slots
+    pub referral_qualified: bool, // Whether a completed goal unlocked the referral bonus
     pub bump: u8,
 }
 
 impl Space for UserAccount {
-    const INIT_SPACE: usize =  32 + 8 + 8 + 8 + 8 + 8 + 1;
+    const INIT_SPACE: usize =  32 + 8 + 8 + 8 + 8 + 8 + 2 + 2 + 8 + 2 + 2 + 2 + 8 + 32 + 1 + 8 + 8 + 8 + 8 + 2 + 1 + 1;
 }
\ No newline at end of file
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Debug)]
+pub struct VestingAccount {
+    pub owner: Pubkey,
+    pub carry_amount: u64, // Unlocked base units from previous schedules that were not withdrawn yet
+    pub schedule_amount: u64, // Base units locked in the current schedule
+    pub schedule_withdrawn: u64, // Base units of the current schedule already withdrawn
+    pub start_timestamp: i64, // Start of the current schedule, weighted by amount when a claim joins it
+    pub cliff: i64, // Seconds after start before anything unlocks, kept until the schedule is done
+    pub duration: i64, // Seconds after start until everything is unlocked
+    pub bump: u8,
+}
+
+impl Space for VestingAccount {
+    const INIT_SPACE: usize = 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+}
@@ -1,13 +1,20 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
 use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
 
-use crate::state::{UserAccount, GoalAccount, Config};
+use crate::errors::MomentumFiError;
+use crate::state::{UserAccount, GoalAccount, GoalKind, Config, StakeAccount, Leaderboard, LeaderboardEntry, Season, GuardianLink, QuestTemplate};
 
 // Constants
 pub const MAXIMUM_AGE: u64 = 1800; // 30 Minutes
 pub const FEED_ID: &str = "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
 pub const LAMPORTS_PER_SOL: u128 = 1_000_000_000; 
 pub const PRICE_PER_SOL_PRECISION: u128 = 1_000_000_00; 
+pub const BASIS_POINTS: u128 = 10_000;
+pub const SECONDS_PER_DAY: i64 = 86_400;
+pub const HOLD_OBSERVATION_GRACE: i64 = 60 * 60; // Hold streaks survive observations up to a day plus this apart
+pub const REFERRAL_MIN_GOAL_AGE: i64 = SECONDS_PER_DAY; // Goals completed sooner don't unlock the referral bonus
+pub const MIN_DIFFICULTY_DURATION: i64 = SECONDS_PER_DAY; // Shorter goals always get the flat completion bonus
 
 // Function to get the current SOL price and update the config
 pub fn update_sol_price<'info>(
@@ -33,19 +40,526 @@ pub fn update_user_account<'info>(
     config: &Account<'info, Config>,
 ) -> Result<()> {
     user_account.sol_balance = user_wallet.lamports();
-    user_account.usd_balance = (user_account.sol_balance as u128 * config.sol_price as u128 / LAMPORTS_PER_SOL / PRICE_PER_SOL_PRECISION) as u64;
+    user_account.usd_balance = lamports_to_usd(user_account.sol_balance, config.sol_price);
     msg!("User USD balance is now: {}", user_account.usd_balance);
 
     Ok(())
 }
 
+// Converts lamports to USD at the cached SOL price
+pub fn lamports_to_usd(lamports: u64, sol_price: u64) -> u64 {
+    (lamports as u128 * sol_price as u128 / LAMPORTS_PER_SOL / PRICE_PER_SOL_PRECISION) as u64
+}
+
+// Balances a goal is evaluated against, the wallet plus the SOL saved in the goal's vault
+pub fn goal_balances(user_account: &UserAccount, goal_account: &GoalAccount, sol_price: u64) -> (u64, u64) {
+    let sol_balance = user_account.sol_balance.saturating_add(goal_account.vault_lamports);
+    (lamports_to_usd(sol_balance, sol_price), sol_balance)
+}
+
+// Computes the USD target of a growth goal from the baseline balance captured at creation
+pub fn growth_target_usd(baseline_usd: u64, growth_bps: u16) -> Result<u64> {
+    let target = (baseline_usd as u128)
+        .checked_mul(BASIS_POINTS + growth_bps as u128)
+        .ok_or(MomentumFiError::ArithmeticOverflow)?
+        / BASIS_POINTS;
+
+    u64::try_from(target).map_err(|_| MomentumFiError::ArithmeticOverflow.into())
+}
+
+// Credits points to the user's claimable rewards and lifetime total
+pub fn award_points(user_account: &mut UserAccount, points: u64) -> Result<()> {
+    user_account.claimable_rewards = user_account.claimable_rewards.checked_add(points).ok_or(MomentumFiError::ArithmeticOverflow)?;
+    user_account.total_points = user_account.total_points.checked_add(points).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+// Returns how many base units can still be minted under the supply cap and the current epoch budget.
+// Moves the schedule to the current epoch first.
+pub fn available_emissions(config: &mut Config, current_timestamp: i64) -> u64 {
+    let cap_remaining = if config.max_supply == 0 {
+        u64::MAX
+    } else {
+        config.max_supply.saturating_sub(config.total_minted)
+    };
+
+    if config.epoch_duration == 0 {
+        return cap_remaining;
+    }
+
+    let epoch = (current_timestamp.saturating_sub(config.emissions_start) / config.epoch_duration) as u64;
+    if epoch != config.current_epoch {
+        config.current_epoch = epoch;
+        config.epoch_minted = 0;
+    }
+
+    // Budget halves every halving_interval epochs
+    let budget = match config.halving_interval {
+        0 => config.epoch_emission_budget,
+        interval => config.epoch_emission_budget.checked_shr((epoch / interval as u64) as u32).unwrap_or(0),
+    };
+
+    cap_remaining.min(budget.saturating_sub(config.epoch_minted))
+}
+
+// Records minted base units against the supply cap and the epoch budget
+pub fn record_emissions(config: &mut Config, amount: u64) -> Result<()> {
+    config.total_minted = config.total_minted.checked_add(amount).ok_or(MomentumFiError::ArithmeticOverflow)?;
+    config.epoch_minted = config.epoch_minted.checked_add(amount).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+// Amount of a linear vesting schedule unlocked at the given time, nothing unlocks before the cliff
+pub fn vested_amount(total: u64, start_timestamp: i64, cliff: i64, duration: i64, current_timestamp: i64) -> u64 {
+    let elapsed = current_timestamp.saturating_sub(start_timestamp);
+    if elapsed < cliff {
+        0
+    } else if elapsed >= duration {
+        total
+    } else {
+        (total as u128 * elapsed as u128 / duration as u128) as u64
+    }
+}
+
+// Multiplier in basis points from the user's stake, only paid while the lock is running so a stake
+// can't be added for one update and withdrawn right after. Returns 1x without a locked stake.
+pub fn stake_boost_bps(config: &Config, stake_account: Option<&StakeAccount>, current_timestamp: i64) -> u64 {
+    let Some(stake_account) = stake_account else {
+        return BASIS_POINTS as u64;
+    };
+    if stake_account.amount == 0 || stake_account.lock_until <= current_timestamp {
+        return BASIS_POINTS as u64;
+    }
+
+    let amount_boost = match config.stake_boost_unit {
+        0 => 0,
+        unit => (stake_account.amount / unit).saturating_mul(config.stake_boost_bps_per_unit as u64),
+    };
+
+    let lock_boost = ((stake_account.lock_duration / SECONDS_PER_DAY) as u64).saturating_mul(config.lock_boost_bps_per_day as u64);
+
+    BASIS_POINTS as u64 + amount_boost.saturating_add(lock_boost).min(config.max_stake_boost_bps as u64)
+}
+
+// Applies a multiplier in basis points to a number of points
+pub fn apply_boost(points: u64, boost_bps: u64) -> Result<u64> {
+    let boosted = (points as u128)
+        .checked_mul(boost_bps as u128)
+        .ok_or(MomentumFiError::ArithmeticOverflow)?
+        / BASIS_POINTS;
+
+    u64::try_from(boosted).map_err(|_| MomentumFiError::ArithmeticOverflow.into())
+}
+
+// Whether a goal that just completed unlocks the referral bonus. Throwaway goals that complete on the
+// first update don't count: the goal has to be open for a while and aim above its starting balance.
+pub fn qualifies_for_referral(goal_account: &GoalAccount) -> bool {
+    let target_above_baseline = match goal_account.kind {
+        GoalKind::Target | GoalKind::Growth => goal_account.target_usd > goal_account.baseline_usd,
+        GoalKind::TargetSol => goal_account.target_sol > goal_account.baseline_sol,
+        GoalKind::StayAbove | GoalKind::HoldDuration => true,
+    };
+
+    target_above_baseline && goal_account.completed_timestamp - goal_account.creation_timestamp >= REFERRAL_MIN_GOAL_AGE
+}
+
+// Credits the referral bonus to the referrer once the referred user completed a qualifying goal.
+// The bonus stays pending until an update includes the referrer's account.
+pub fn pay_referral_bonus(user_account: &mut UserAccount, referrer_account: Option<&mut UserAccount>, config: &Config) -> Result<()> {
+    if user_account.referrer == Pubkey::default() || user_account.referral_bonus_paid || !user_account.referral_qualified {
+        return Ok(());
+    }
+
+    let Some(referrer_account) = referrer_account else {
+        msg!("Referral bonus pending, the referrer account was not provided");
+        return Ok(());
+    };
+
+    award_points(referrer_account, config.referral_bonus_points)?;
+    user_account.referral_bonus_paid = true;
+    msg!("Referral bonus of {} points paid to {}", config.referral_bonus_points, referrer_account.owner);
+
+    Ok(())
+}
+
+// Takes a goal slot for a new goal. Users get config.goal_slots open goals plus one per redeemed extra goal slot.
+pub fn open_goal_slot(user_account: &mut UserAccount, config: &Config) -> Result<()> {
+    if config.goal_slots > 0 {
+        let slots = config.goal_slots as u32 + user_account.extra_goal_slots as u32;
+        require!((user_account.open_goals as u32) < slots, MomentumFiError::NoGoalSlot);
+    }
+
+    user_account.open_goals = user_account.open_goals.checked_add(1).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+// Frees the slot of a deleted goal, goals created before slots were counted never took one
+pub fn close_goal_slot(user_account: &mut UserAccount) {
+    user_account.open_goals = user_account.open_goals.saturating_sub(1);
+}
+
+// Counts points earned while the season is running towards the user's share of the season pool.
+// Unclaimed points of an earlier season move to the last season slot. While that slot still holds unclaimed
+// points the new season points are not recorded, so the rest of the reward update goes through.
+pub fn record_season_points(user_account: &mut UserAccount, season: &mut Season, points: u64, current_timestamp: i64) -> Result<()> {
+    if points == 0 || current_timestamp < season.start_timestamp || current_timestamp >= season.end_timestamp {
+        return Ok(());
+    }
+
+    if user_account.season_number != season.season_number {
+        if user_account.season_points > 0 {
+            if user_account.last_season_points > 0 {
+                msg!("Season {} points not recorded, claim season {} first", season.season_number, user_account.last_season_number);
+                return Ok(());
+            }
+            user_account.last_season_number = user_account.season_number;
+            user_account.last_season_points = user_account.season_points;
+        }
+        user_account.season_number = season.season_number;
+        user_account.season_points = 0;
+    }
+
+    user_account.season_points = user_account.season_points.checked_add(points).ok_or(MomentumFiError::ArithmeticOverflow)?;
+    season.total_points = season.total_points.checked_add(points).ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+// Keeps the leaderboard sorted after a user's points changed. Only the user's own slot moves,
+// so this is a single bounded pass over the fixed-size entries.
+pub fn update_leaderboard(leaderboard: &mut Leaderboard, user: Pubkey, points: u64) {
+    let entries = &mut leaderboard.entries;
+    let last = entries.len() - 1;
+
+    let mut index = match entries.iter().position(|entry| entry.user == user) {
+        Some(index) => index,
+        None => {
+            // Not ranked yet, take the last slot if the user beats it
+            if points == 0 || points <= entries[last].points {
+                return;
+            }
+            last
+        }
+    };
+
+    entries[index] = LeaderboardEntry { user, points };
+
+    // Points only grow, so the entry can only move up
+    while index > 0 && entries[index - 1].points < points {
+        entries.swap(index - 1, index);
+        index -= 1;
+    }
+}
+
+// Whether the points of a goal accrue to the guardian instead of the user.
+// Guardian-managed goals need the link, so the guardian's choice cannot be skipped by leaving it out.
+pub fn rewards_to_guardian(goal_account: &GoalAccount, guardian_link: Option<&GuardianLink>) -> Result<bool> {
+    if goal_account.guardian == Pubkey::default() {
+        return Ok(false);
+    }
+
+    let guardian_link = guardian_link
+        .filter(|link| link.guardian == goal_account.guardian)
+        .ok_or(MomentumFiError::GuardianLinkRequired)?;
+
+    Ok(guardian_link.rewards_to_guardian)
+}
+
+// Credits the points routed to the guardian by `rewards_to_guardian`
+pub fn award_guardian_points(guardian_account: Option<&mut UserAccount>, guardian_link: Option<&GuardianLink>, points: u64) -> Result<()> {
+    if points == 0 {
+        return Ok(());
+    }
+
+    let guardian_account = guardian_account.ok_or(MomentumFiError::GuardianAccountRequired)?;
+    let guardian_link = guardian_link.ok_or(MomentumFiError::GuardianLinkRequired)?;
+    require!(guardian_account.owner == guardian_link.guardian, MomentumFiError::GuardianAccountRequired);
+
+    award_points(guardian_account, points)?;
+    msg!("{} points credited to the guardian {}", points, guardian_account.owner);
+
+    Ok(())
+}
+
+// Resets a quest goal to the given step, which starts from the user's current balances
+pub fn apply_quest_step(goal_account: &mut GoalAccount, quest_template: &QuestTemplate, step: u8, user_account: &UserAccount, current_timestamp: i64) {
+    let quest_step = quest_template.steps[step as usize];
+
+    goal_account.kind = quest_step.kind;
+    goal_account.target_usd = quest_step.target_usd;
+    goal_account.target_sol = quest_step.target_sol;
+    goal_account.hold_days = quest_step.hold_days;
+    goal_account.quest_step = step;
+    goal_account.creation_timestamp = current_timestamp;
+    goal_account.deadline = current_timestamp.saturating_add(quest_template.step_duration);
+    goal_account.baseline_usd = user_account.usd_balance;
+    goal_account.baseline_sol = user_account.sol_balance;
+    goal_account.completed = false;
+    goal_account.first_completed_bonus = false;
+    goal_account.completed_timestamp = 0;
+    goal_account.last_daily_reward_timestamp = 0;
+    goal_account.streak_start_timestamp = 0;
+    goal_account.streak_days = 0;
+    goal_account.daily_streak = 0;
+    goal_account.last_hold_observation = 0;
+}
+
+// Moves a quest goal whose step just completed on to the next step.
+// Returns the chain bonus once the last step completed, the goal then stays completed.
+pub fn advance_quest(goal_account: &mut GoalAccount, quest_template: Option<&Account<QuestTemplate>>, user_account: &UserAccount, current_timestamp: i64) -> Result<u64> {
+    if goal_account.quest == Pubkey::default() {
+        return Ok(0);
+    }
+
+    let quest_template = quest_template
+        .filter(|template| template.key() == goal_account.quest)
+        .ok_or(MomentumFiError::QuestTemplateRequired)?;
+    let next_step = goal_account.quest_step + 1;
+
+    if next_step >= quest_template.step_count {
+        msg!("Quest {} completed, chain bonus of {} points", quest_template.quest_id, quest_template.chain_bonus_points);
+        return Ok(quest_template.chain_bonus_points);
+    }
+
+    apply_quest_step(goal_account, quest_template, next_step, user_account, current_timestamp);
+    msg!("Quest {} advanced to step {}", quest_template.quest_id, next_step);
+
+    Ok(0)
+}
+
+// Daily points of a goal, templates can override the configured value
+pub fn goal_daily_points(config: &Config, goal_account: &GoalAccount) -> u64 {
+    match goal_account.daily_points_override {
+        0 => config.daily_points as u64,
+        points => points,
+    }
+}
+
+// First completion points of a goal, templates can override the configured value
+pub fn goal_first_completed_points(config: &Config, goal_account: &GoalAccount) -> u64 {
+    match goal_account.first_completed_points_override {
+        0 => config.first_completed_points as u64,
+        points => points,
+    }
+}
+
+// Multiplier in basis points applied to the first completion points.
+// Grows with the gap between the baseline and the target, scaled up for goals shorter than the reference duration.
+// Floor and hold goals are about keeping a balance, so they are not scaled.
+pub fn difficulty_bps(config: &Config, goal_account: &GoalAccount) -> u128 {
+    if config.max_difficulty_bps == 0 {
+        return BASIS_POINTS;
+    }
+
+    // Challenge, quest and guardian goals take the cached balances as baseline, which can be stale
+    if goal_account.challenge != Pubkey::default()
+        || goal_account.quest != Pubkey::default()
+        || goal_account.guardian != Pubkey::default()
+    {
+        return BASIS_POINTS;
+    }
+
+    let (baseline, target) = match goal_account.kind {
+        GoalKind::Target | GoalKind::Growth => (goal_account.baseline_usd, goal_account.target_usd),
+        GoalKind::TargetSol => (goal_account.baseline_sol, goal_account.target_sol),
+        GoalKind::StayAbove | GoalKind::HoldDuration => return BASIS_POINTS,
+    };
+
+    // Very short goals would otherwise be scaled up by the time to deadline
+    let duration = goal_account.deadline - goal_account.creation_timestamp;
+    if duration < MIN_DIFFICULTY_DURATION {
+        return BASIS_POINTS;
+    }
+
+    // An empty baseline has no relative gap to measure, so it is scaled like an easy goal
+    if baseline == 0 || target <= baseline {
+        return config.min_difficulty_bps as u128;
+    }
+
+    // Relative gap in basis points
+    let gap_bps = (target - baseline) as u128 * BASIS_POINTS / baseline as u128;
+    let mut bonus_bps = gap_bps.saturating_mul(config.difficulty_gap_weight_bps as u128) / BASIS_POINTS;
+
+    if config.difficulty_reference_days > 0 {
+        let days = (duration / SECONDS_PER_DAY) as u128;
+        bonus_bps = bonus_bps.saturating_mul(config.difficulty_reference_days as u128) / days;
+    }
+
+    BASIS_POINTS.saturating_add(bonus_bps).min(config.max_difficulty_bps as u128)
+}
+
+// Points paid once when a goal completes, expects the completion timestamp to be set.
+// Hold goals get the daily points of the whole streak as a bonus at the end.
+pub fn completion_points(config: &Config, goal_account: &GoalAccount) -> Result<u64> {
+    let points = match goal_account.kind {
+        GoalKind::HoldDuration => goal_daily_points(config, goal_account)
+            .checked_mul(goal_account.hold_days as u64)
+            .ok_or(MomentumFiError::ArithmeticOverflow)?,
+        _ => 0,
+    };
+
+    let first_completed_points = (goal_first_completed_points(config, goal_account) as u128)
+        .checked_mul(difficulty_bps(config, goal_account))
+        .map(|points| points / BASIS_POINTS)
+        .and_then(|points| u64::try_from(points).ok())
+        .ok_or(MomentumFiError::ArithmeticOverflow)?;
+
+    // The challenge reward is only paid when the goal completed before its deadline
+    let challenge_reward_points = if completed_in_time(goal_account) {
+        goal_account.challenge_reward_points
+    } else {
+        0
+    };
+
+    points
+        .checked_add(first_completed_points)
+        .and_then(|points| points.checked_add(challenge_reward_points))
+        .ok_or(MomentumFiError::ArithmeticOverflow.into())
+}
+
+// Number of whole days skipped between two rewarded days
+pub fn missed_days(last_timestamp: i64, current_timestamp: i64) -> i64 {
+    if last_timestamp == 0 {
+        0
+    } else {
+        ((current_timestamp - last_timestamp) / SECONDS_PER_DAY - 1).max(0)
+    }
+}
+
+// Returns the streak after a new rewarded day. The streak resets when more days were missed than bridged by streak freezes.
+pub fn next_streak(streak: u16, last_timestamp: i64, current_timestamp: i64, bridged_days: u16) -> u16 {
+    if last_timestamp != 0 && missed_days(last_timestamp, current_timestamp) <= bridged_days as i64 {
+        streak.saturating_add(1)
+    } else {
+        1
+    }
+}
+
+// Applies the highest streak multiplier tier reached to the daily points
+pub fn daily_points_for_streak(config: &Config, goal_account: &GoalAccount) -> Result<u64> {
+    let streak = goal_account.daily_streak;
+    let multiplier_bps = config
+        .streak_multipliers
+        .iter()
+        .filter(|tier| tier.min_days > 0 && streak >= tier.min_days)
+        .map(|tier| tier.multiplier_bps as u128)
+        .max()
+        .unwrap_or(BASIS_POINTS);
+
+    let points = (goal_daily_points(config, goal_account) as u128)
+        .checked_mul(multiplier_bps)
+        .ok_or(MomentumFiError::ArithmeticOverflow)?
+        / BASIS_POINTS;
+
+    u64::try_from(points).map_err(|_| MomentumFiError::ArithmeticOverflow.into())
+}
+
 // Checks if a goal is completed based on the user's USD balance.
+// Floor goals record the first breach and only complete at the deadline if the floor was never breached.
+// Hold goals track the streak above the target and complete once it lasted hold_days.
 // Returns `true` if the goal is completed, otherwise `false`.
-pub fn is_goal_completed(user_usd_balance: u64, goal_account: &mut GoalAccount) -> bool {
-    if user_usd_balance >= goal_account.target_usd {
-        msg!("Goal completed! Target was: {} The USD balance is now: {}", goal_account.target_usd, user_usd_balance);
-        true
-    } else {
-        false
+pub fn is_goal_completed(user_usd_balance: u64, user_sol_balance: u64, goal_account: &mut GoalAccount, current_timestamp: i64) -> bool {
+    match goal_account.kind {
+        GoalKind::TargetSol => {
+            if user_sol_balance >= goal_account.target_sol {
+                msg!("Goal completed! Target was: {} lamports The SOL balance is now: {}", goal_account.target_sol, user_sol_balance);
+                true
+            } else {
+                false
+            }
+        }
+        GoalKind::Target | GoalKind::Growth => {
+            if user_usd_balance >= goal_account.target_usd {
+                msg!("Goal completed! Target was: {} The USD balance is now: {}", goal_account.target_usd, user_usd_balance);
+                true
+            } else {
+                false
+            }
+        }
+        GoalKind::StayAbove => {
+            if !goal_account.breached && current_timestamp <= goal_account.deadline && user_usd_balance < goal_account.target_usd {
+                goal_account.breached = true;
+                goal_account.breach_timestamp = current_timestamp;
+                goal_account.breach_usd_balance = user_usd_balance;
+                msg!("Floor breached! Floor was: {} The USD balance is now: {}", goal_account.target_usd, user_usd_balance);
+            }
+
+            if !goal_account.breached && current_timestamp >= goal_account.deadline {
+                msg!("Goal completed! The USD balance stayed above {} until the deadline", goal_account.target_usd);
+                true
+            } else {
+                false
+            }
+        }
+        GoalKind::HoldDuration => {
+            if user_usd_balance >= goal_account.target_usd {
+                // Days are only confirmed by daily observations, a missed day could have hidden a dip below target
+                let observation_missed = current_timestamp - goal_account.last_hold_observation > SECONDS_PER_DAY + HOLD_OBSERVATION_GRACE;
+                if goal_account.streak_start_timestamp == 0 || observation_missed {
+                    goal_account.streak_start_timestamp = current_timestamp;
+                }
+                goal_account.last_hold_observation = current_timestamp;
+                let days = (current_timestamp - goal_account.streak_start_timestamp) / SECONDS_PER_DAY;
+                goal_account.streak_days = u16::try_from(days).unwrap_or(u16::MAX);
+            } else {
+                // Streak broken, start over the next time the balance is above target
+                goal_account.streak_start_timestamp = 0;
+                goal_account.streak_days = 0;
+                goal_account.last_hold_observation = 0;
+            }
+
+            if goal_account.streak_days >= goal_account.hold_days {
+                msg!("Goal completed! The USD balance stayed above {} for {} days", goal_account.target_usd, goal_account.streak_days);
+                true
+            } else {
+                false
+            }
+        }
     }
 }
+
+// Whether a goal completed in time for its stake to be returned. Floor goals only complete at the deadline,
+// so any completion counts for them.
+pub fn completed_in_time(goal_account: &GoalAccount) -> bool {
+    goal_account.first_completed_bonus
+        && (goal_account.kind == GoalKind::StayAbove || goal_account.completed_timestamp <= goal_account.deadline)
+}
+
+// Whether a goal failed and its stake can be forfeited. Floor goals fail on a recorded breach,
+// a floor that held until the deadline only completes at the next update.
+pub fn stake_forfeitable(goal_account: &GoalAccount, current_timestamp: i64) -> bool {
+    match goal_account.kind {
+        GoalKind::StayAbove => goal_account.breached,
+        _ => current_timestamp > goal_account.deadline && !completed_in_time(goal_account),
+    }
+}
+
+// Moves the whole goal stake escrow to the destination, signed by the escrow PDA
+pub fn release_goal_stake<'info>(
+    goal_stake: &SystemAccount<'info>,
+    destination: AccountInfo<'info>,
+    goal_account: &Account<'info, GoalAccount>,
+    bump: u8,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let goal_key = goal_account.key();
+    let seeds = &[
+        &b"goal_stake"[..],
+        goal_key.as_ref(),
+        &[bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        system_program.to_account_info(),
+        Transfer {
+            from: goal_stake.to_account_info(),
+            to: destination,
+        },
+        signer_seeds,
+    );
+    transfer(cpi_context, goal_stake.lamports())
+}
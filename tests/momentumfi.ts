@@ -5,6 +5,7 @@ import { Momentumfi } from "../target/types/momentumfi";
 import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { expect } from "chai";
 import { TOKEN_PROGRAM_ID, 
+  TOKEN_2022_PROGRAM_ID, 
   createMint, 
   createAccount, 
   mintTo, 
@@ -15,6 +16,9 @@ import { TOKEN_PROGRAM_ID,
   getOrCreateAssociatedTokenAccount
  } from "@solana/spl-token";
 
+// Shared by all suites since the config authority is fixed once the config exists
+const configAdmin = Keypair.generate();
+
 
 describe("MomentumFi - Config Tests", () => {
   // Configure the client to use the local cluster.
@@ -24,7 +28,7 @@ describe("MomentumFi - Config Tests", () => {
   const program = anchor.workspace.Momentumfi as Program<Momentumfi>;
   
   // Test accounts
-  const admin = Keypair.generate();
+  const admin = configAdmin; // The config can only be re-initialized by its authority
   const nonAdmin = Keypair.generate();
   let testUser: Keypair;
 
@@ -66,6 +70,25 @@ describe("MomentumFi - Config Tests", () => {
     );
   });
 
+  // Re-initializing keeps the settings, so restore the defaults the other suites expect
+  after(async () => {
+    const restore = [
+      program.methods.setStreakMultipliers(Array(4).fill({ minDays: 0, multiplierBps: 0 })),
+      program.methods.setPointsConversionRate(new anchor.BN(10 ** 6)),
+      program.methods.setDifficultyCurve(0, 0, 0, 0),
+    ];
+    for (const method of restore) {
+      await method
+        .accounts({
+          admin: admin.publicKey,
+          config: configPDA,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([admin])
+        .rpc();
+    }
+  });
+
   it("Test Initialize admin configuration", async () => {
     const firstCompletedPoints = 1000;
     const dailyPoints = 50;
@@ -75,7 +98,9 @@ describe("MomentumFi - Config Tests", () => {
       await program.methods
         .initialize(
           firstCompletedPoints,
-          dailyPoints
+          dailyPoints,
+          false,
+          false
         )
         .accounts({
           admin: admin.publicKey,
@@ -105,7 +130,9 @@ describe("MomentumFi - Config Tests", () => {
       await program.methods
         .initialize(
           firstCompletedPoints,
-          dailyPoints
+          dailyPoints,
+          false,
+          false
         )
         .accounts({
           admin: admin.publicKey,
@@ -126,6 +153,312 @@ describe("MomentumFi - Config Tests", () => {
       expect(error.message).to.include("Please set a points per goal value less than 10000");
     }
   });
+
+  it("Test admin sets streak multipliers", async () => {
+    const streakMultipliers = [
+      { minDays: 7, multiplierBps: 12_500 },
+      { minDays: 30, multiplierBps: 15_000 },
+      { minDays: 100, multiplierBps: 20_000 },
+      { minDays: 0, multiplierBps: 0 },
+    ];
+
+    await program.methods
+      .setStreakMultipliers(streakMultipliers)
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    const configAccount = await program.account.config.fetch(configPDA);
+    expect(configAccount.streakMultipliers[1].minDays).to.equal(30);
+    expect(configAccount.streakMultipliers[1].multiplierBps).to.equal(15_000);
+  });
+
+  it("Test non-admin cannot set streak multipliers", async () => {
+    const streakMultipliers = Array(4).fill({ minDays: 0, multiplierBps: 0 });
+
+    try {
+      await program.methods
+        .setStreakMultipliers(streakMultipliers)
+        .accounts({
+          admin: nonAdmin.publicKey,
+          config: configPDA,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([nonAdmin])
+        .rpc();
+
+      expect.fail("Expected transaction to fail for a non-admin, but it succeeded.");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("UnauthorizedAccess");
+    }
+  });
+
+  it("Test admin creates and updates the rewards mint metadata", async () => {
+    const tokenMetadataProgram = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+    const [metadataPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), tokenMetadataProgram.toBuffer(), rewardsMintPDA.toBuffer()],
+      tokenMetadataProgram
+    );
+
+    await program.methods
+      .createRewardsMetadata("MomentumFi Points", "MOMO", "https://momentumfi.xyz/token.json")
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        rewardsMint: rewardsMintPDA,
+        metadata: metadataPDA,
+        tokenMetadataProgram,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .updateRewardsMetadata("MomentumFi Rewards", "MOMO", "https://momentumfi.xyz/token.json")
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        rewardsMint: rewardsMintPDA,
+        metadata: metadataPDA,
+        tokenMetadataProgram,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    const metadataAccount = await provider.connection.getAccountInfo(metadataPDA);
+    expect(metadataAccount.owner.toString()).to.equal(tokenMetadataProgram.toString());
+    expect(metadataAccount.data.toString()).to.include("MomentumFi Rewards");
+  });
+
+  it("Test soulbound rewards mint requires the Token-2022 program", async () => {
+    try {
+      await program.methods
+        .initialize(1000, 50, true, false)
+        .accounts({
+          admin: admin.publicKey,
+          configAccount: configPDA,
+          rewardsMint: rewardsMintPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([admin])
+        .rpc();
+
+      expect.fail("Expected transaction to fail with the legacy token program, but it succeeded.");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("InvalidTokenProgram");
+    }
+  });
+
+  it("Test admin sets the points conversion rate", async () => {
+    await program.methods
+      .setPointsConversionRate(new anchor.BN(500_000)) // 1 point = 0.5 token
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    const configAccount = await program.account.config.fetch(configPDA);
+    expect(configAccount.pointsConversionRate.toNumber()).to.equal(500_000);
+  });
+
+  it("Test admin sets the emission schedule", async () => {
+    const maxSupply = new anchor.BN(1_000_000_000_000);
+    const epochDuration = new anchor.BN(60 * 60 * 24 * 7); // Weekly epochs
+    const epochBudget = new anchor.BN(10_000_000_000);
+
+    await program.methods
+      .setEmissionSchedule(maxSupply, epochDuration, epochBudget, 52)
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    const configAccount = await program.account.config.fetch(configPDA);
+    expect(configAccount.maxSupply.eq(maxSupply)).to.be.true;
+    expect(configAccount.epochDuration.eq(epochDuration)).to.be.true;
+    expect(configAccount.epochEmissionBudget.eq(epochBudget)).to.be.true;
+    expect(configAccount.halvingInterval).to.equal(52);
+    expect(configAccount.emissionsStart.toNumber()).to.be.greaterThan(0);
+  });
+
+  it("Test admin initializes the leaderboard", async () => {
+    const [leaderboardPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("leaderboard"), configPDA.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initLeaderboard()
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        leaderboard: leaderboardPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    const leaderboard = await program.account.leaderboard.fetch(leaderboardPDA);
+    expect(leaderboard.entries.length).to.equal(10);
+    expect(leaderboard.entries[0].points.toNumber()).to.equal(0);
+  });
+
+  it("Test admin starts a season and cannot overlap it with the next one", async () => {
+    const configBefore = await program.account.config.fetch(configPDA);
+    const seasonNumber = configBefore.currentSeason.toNumber() + 1;
+    const seasonPDA = (n: number) => PublicKey.findProgramAddressSync(
+      [Buffer.from("season"), configPDA.toBuffer(), new anchor.BN(n).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+    const now = Math.floor(Date.now() / 1000);
+
+    await program.methods
+      .startSeason(new anchor.BN(now), new anchor.BN(now + 86400 * 30), new anchor.BN(1_000_000_000))
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        currentSeason: seasonNumber > 1 ? seasonPDA(seasonNumber - 1) : null,
+        nextSeason: seasonPDA(seasonNumber),
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    const season = await program.account.season.fetch(seasonPDA(seasonNumber));
+    expect(season.seasonNumber.toNumber()).to.equal(seasonNumber);
+    expect(season.rewardPool.toNumber()).to.equal(1_000_000_000);
+
+    // Neither an overlapping season nor one scheduled after the running season can be started yet
+    for (const start of [now + 86400, now + 86400 * 30]) {
+      try {
+        await program.methods
+          .startSeason(new anchor.BN(start), new anchor.BN(start + 86400 * 30), new anchor.BN(1_000_000_000))
+          .accounts({
+            admin: admin.publicKey,
+            config: configPDA,
+            currentSeason: seasonPDA(seasonNumber),
+            nextSeason: seasonPDA(seasonNumber + 1),
+            systemProgram: SystemProgram.programId,
+          } as any)
+          .signers([admin])
+          .rpc();
+        expect.fail("Expected season in progress error");
+      } catch (error) {
+        expect(error.message).to.include("SeasonInProgress");
+      }
+    }
+
+    const config = await program.account.config.fetch(configPDA);
+    expect(config.currentSeason.toNumber()).to.equal(seasonNumber);
+  });
+
+  it("Test admin sets the difficulty curve", async () => {
+    await program.methods
+      .setDifficultyCurve(5_000, 30, 5_000, 30_000)
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    const configAccount = await program.account.config.fetch(configPDA);
+    expect(configAccount.difficultyGapWeightBps).to.equal(5_000);
+    expect(configAccount.maxDifficultyBps).to.equal(30_000);
+
+    try {
+      await program.methods
+        .setDifficultyCurve(5_000, 30, 5_000, 60_000)
+        .accounts({
+          admin: admin.publicKey,
+          config: configPDA,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([admin])
+        .rpc();
+      expect.fail("Expected invalid difficulty curve error");
+    } catch (error) {
+      expect(error.message).to.include("InvalidDifficultyCurve");
+    }
+  });
+
+  it("Test non-admin cannot re-initialize the config", async () => {
+    try {
+      await program.methods
+        .initialize(1000, 50, false, false)
+        .accounts({
+          admin: nonAdmin.publicKey,
+          configAccount: configPDA,
+          rewardsMint: rewardsMintPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([nonAdmin])
+        .rpc();
+      expect.fail("Expected unauthorized access error");
+    } catch (error) {
+      expect(error.message).to.include("UnauthorizedAccess");
+    }
+
+    const configAccount = await program.account.config.fetch(configPDA);
+    expect(configAccount.authority.toString()).to.equal(admin.publicKey.toString());
+    expect(configAccount.treasury.toString()).to.equal(admin.publicKey.toString());
+  });
+
+  it("Test re-initializing cannot change the rewards mint extensions", async () => {
+    try {
+      await program.methods
+        .initialize(1000, 50, true, false)
+        .accounts({
+          admin: admin.publicKey,
+          configAccount: configPDA,
+          rewardsMint: rewardsMintPDA,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([admin])
+        .rpc();
+
+      expect.fail("Expected transaction to fail for the existing mint, but it succeeded.");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("RewardsMintMismatch");
+    }
+  });
+
+  it("Test re-initializing only updates the points and keeps the other settings", async () => {
+    const configBefore = await program.account.config.fetch(configPDA);
+
+    await program.methods
+      .initialize(1200, 60, false, false)
+      .accounts({
+        admin: admin.publicKey,
+        configAccount: configPDA,
+        rewardsMint: rewardsMintPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    const configAfter = await program.account.config.fetch(configPDA);
+    expect(configAfter.firstCompletedPoints).to.equal(1200);
+    expect(configAfter.dailyPoints).to.equal(60);
+    expect(configAfter.pointsConversionRate.eq(configBefore.pointsConversionRate)).to.be.true;
+    expect(configAfter.maxDifficultyBps).to.equal(configBefore.maxDifficultyBps);
+    expect(configAfter.streakMultipliers[1].multiplierBps).to.equal(configBefore.streakMultipliers[1].multiplierBps);
+    expect(configAfter.treasury.toString()).to.equal(configBefore.treasury.toString());
+  });
 });
 
 describe("MomentumFi - Test register user", () => {
@@ -136,7 +469,7 @@ describe("MomentumFi - Test register user", () => {
   const program = anchor.workspace.Momentumfi as Program<Momentumfi>;
   
   // Test accounts
-  const admin = Keypair.generate();
+  const admin = configAdmin; // The config can only be re-initialized by its authority
   let firstUser: Keypair;
   let secondUser: Keypair;
   let thirdUser: Keypair;
@@ -206,7 +539,9 @@ describe("MomentumFi - Test register user", () => {
       await program.methods
         .initialize(
           firstCompletedPoints,
-          dailyPoints
+          dailyPoints,
+          false,
+          false
         )
         .accounts({
           admin: admin.publicKey,
@@ -371,6 +706,70 @@ describe("MomentumFi - Test register user", () => {
     expect(thirdUserAccount.solBalance.eq(new anchor.BN(thirdUserBalance))).to.be.true;
   });
 
+  it("Test register a user with a referrer", async () => {
+    await program.methods
+      .registerUserAccount()
+      .accounts({
+        user: firstUser.publicKey,
+        userAccount: firstUserAccountPDA,
+        configAccount: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([firstUser])
+      .rpc();
+
+    // Second user was invited by the first one
+    await program.methods
+      .registerUserAccount()
+      .accounts({
+        user: secondUser.publicKey,
+        userAccount: secondUserAccountPDA,
+        configAccount: configPDA,
+        referrerAccount: firstUserAccountPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([secondUser])
+      .rpc();
+
+    const secondUserAccount = await program.account.userAccount.fetch(secondUserAccountPDA);
+    expect(secondUserAccount.referrer.toString()).to.equal(firstUser.publicKey.toString());
+    expect(secondUserAccount.referralBonusPaid).to.be.false;
+    expect(secondUserAccount.referralQualified).to.be.false;
+  });
+
+  it("Test migrating an account that already has the current layout fails", async () => {
+    await program.methods
+      .registerUserAccount()
+      .accounts({
+        user: firstUser.publicKey,
+        userAccount: firstUserAccountPDA,
+        configAccount: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([firstUser])
+      .rpc();
+
+    for (const migrate of [
+      program.methods.migrateAccount().accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        account: firstUserAccountPDA,
+        systemProgram: SystemProgram.programId,
+      } as any),
+      program.methods.migrateConfig().accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any),
+    ]) {
+      try {
+        await migrate.signers([admin]).rpc();
+        expect.fail("Expected invalid migration error");
+      } catch (error) {
+        expect(error.message).to.include("InvalidMigration");
+      }
+    }
+  });
 });
 
 
@@ -383,7 +782,7 @@ describe("MomentumFi - Test Create Goal", () => {
   const program = anchor.workspace.Momentumfi as Program<Momentumfi>;
   
   // Test accounts
-  const admin = Keypair.generate();
+  const admin = configAdmin; // The config can only be re-initialized by its authority
   let testUser: Keypair;
 
   // Declaring PDAs, will be assigned later in the tests
@@ -437,7 +836,9 @@ describe("MomentumFi - Test Create Goal", () => {
       await program.methods
         .initialize(
           firstCompletedPoints,
-          dailyPoints
+          dailyPoints,
+          false,
+          false
         )
         .accounts({
           admin: admin.publicKey,
@@ -488,7 +889,7 @@ describe("MomentumFi - Test Create Goal", () => {
     // Create goal
     try {
       await program.methods
-        .createGoal(goalNumber, targetUsd, deadline)
+        .createGoal(goalNumber, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           user: testUser.publicKey,
           userAccount: userAccountPDA,
@@ -533,7 +934,7 @@ describe("MomentumFi - Test Create Goal", () => {
 
     // Create first goal
     await program.methods
-      .createGoal(goalNumber, new anchor.BN(targetUsd1), new anchor.BN(deadline1))
+      .createGoal(goalNumber, new anchor.BN(targetUsd1), new anchor.BN(deadline1), { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
       .accounts({
         user: testUser.publicKey,
         userAccount: userAccountPDA,
@@ -562,7 +963,7 @@ describe("MomentumFi - Test Create Goal", () => {
 
     // Create second goal
     await program.methods
-      .createGoal(goalNumber, new anchor.BN(targetUsd2), new anchor.BN(deadline2))
+      .createGoal(goalNumber, new anchor.BN(targetUsd2), new anchor.BN(deadline2), { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
       .accounts({
         user: testUser.publicKey,
         userAccount: userAccountPDA,
@@ -598,7 +999,7 @@ describe("MomentumFi - Test Create Goal", () => {
 
     // First goal creation (should succeed)
     await program.methods
-      .createGoal(goalNumber, new anchor.BN(targetUsd), new anchor.BN(deadline))
+      .createGoal(goalNumber, new anchor.BN(targetUsd), new anchor.BN(deadline), { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
       .accounts({
         user: testUser.publicKey,
         userAccount: userAccountPDA,
@@ -611,7 +1012,7 @@ describe("MomentumFi - Test Create Goal", () => {
     // Attempt to create the same goal again (should fail)
     try {
       await program.methods
-        .createGoal(goalNumber, new anchor.BN(targetUsd), new anchor.BN(deadline))
+        .createGoal(goalNumber, new anchor.BN(targetUsd), new anchor.BN(deadline), { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           user: testUser.publicKey,
           userAccount: userAccountPDA,
@@ -627,25 +1028,522 @@ describe("MomentumFi - Test Create Goal", () => {
       expect(error.message).to.include("already in use");
     }
   });
-});
 
-describe("MomentumFi - Test Delete Goal", () => {
-  // Configure the client to use the local cluster.
-  const provider = anchor.AnchorProvider.env();
-  anchor.setProvider(provider);
+  it("Test create a growth goal from the current balance", async () => {
+    const priceFeedPDA = new PublicKey("7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE");
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 60 * 60 * 24 * 30);
+    const goalNumber = new anchor.BN(0);
+    const growthBps = 2000; // 20%
 
-  const program = anchor.workspace.Momentumfi as Program<Momentumfi>;
+    [goalPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("goal_account"), userAccountPDA.toBuffer(), goalNumber.toBuffer('le', 8)],
+      program.programId
+    );
 
-  // Test accounts
-  const admin = Keypair.generate();
-  let testUser: Keypair;
-  let anotherUser: Keypair;
+    await program.methods
+      .createGoal(goalNumber, new anchor.BN(0), deadline, { growth: {} }, growthBps, 0, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        goalAccount: goalPDA,
+        config: configPDA,
+        priceUpdate: priceFeedPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
 
-  // Declaring PDAs
-  let configPDA: PublicKey;
-  let rewardsMintPDA: PublicKey;
-  let userAccountPDA: PublicKey;
-  let goalPDA: PublicKey;
+    const goal = await program.account.goalAccount.fetch(goalPDA);
+    const userAccount = await program.account.userAccount.fetch(userAccountPDA);
+    expect(goal.baselineUsd.eq(userAccount.usdBalance)).to.be.true;
+    expect(goal.baselineSol.eq(userAccount.solBalance)).to.be.true;
+    expect(goal.targetUsd.eq(goal.baselineUsd.muln(10000 + growthBps).divn(10000))).to.be.true;
+  });
+
+  it("Test fail creating a growth goal without a price update", async () => {
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 60 * 60 * 24 * 30);
+    const goalNumber = new anchor.BN(0);
+
+    [goalPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("goal_account"), userAccountPDA.toBuffer(), goalNumber.toBuffer('le', 8)],
+      program.programId
+    );
+
+    try {
+      await program.methods
+        .createGoal(goalNumber, new anchor.BN(0), deadline, { growth: {} }, 2000, 0, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          user: testUser.publicKey,
+          userAccount: userAccountPDA,
+          goalAccount: goalPDA,
+          config: configPDA,
+          priceUpdate: null,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([testUser])
+        .rpc();
+
+      expect.fail("Expected transaction to fail without a price update, but it succeeded.");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("PriceUpdateRequired");
+    }
+  });
+
+  it("Test user joins an admin-created challenge", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const configAccount = await program.account.config.fetch(configPDA);
+    const [challengePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("challenge"), configPDA.toBuffer(), configAccount.challengeCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [challengeEntryPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("challenge_entry"), challengePDA.toBuffer(), userAccountPDA.toBuffer()],
+      program.programId
+    );
+    const goalNumber = new anchor.BN(0);
+    [goalPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("goal_account"), userAccountPDA.toBuffer(), goalNumber.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .createChallenge(
+        { targetSol: {} },
+        new anchor.BN(0),
+        new anchor.BN(5 * LAMPORTS_PER_SOL),
+        new anchor.BN(now - 60),
+        new anchor.BN(now + 86400),
+        new anchor.BN(now + 86400 * 30),
+        new anchor.BN(500)
+      )
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        challenge: challengePDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .joinChallenge(goalNumber)
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        challenge: challengePDA,
+        challengeEntry: challengeEntryPDA,
+        goalAccount: goalPDA,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    const goalAccount = await program.account.goalAccount.fetch(goalPDA);
+    expect(goalAccount.challenge.toString()).to.equal(challengePDA.toString());
+    expect(goalAccount.targetSol.toNumber()).to.equal(5 * LAMPORTS_PER_SOL);
+    expect(goalAccount.challengeRewardPoints.toNumber()).to.equal(500);
+
+    const challenge = await program.account.challenge.fetch(challengePDA);
+    expect(challenge.participants.toNumber()).to.equal(1);
+  });
+
+  it("Test staked goal locks SOL and cannot be deleted", async () => {
+    const goalNumber = new anchor.BN(0);
+    const stakeLamports = 0.1 * LAMPORTS_PER_SOL;
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 86400); // 1 day deadline
+    [goalPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("goal_account"), userAccountPDA.toBuffer(), goalNumber.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [goalStakePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("goal_stake"), goalPDA.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .createGoal(goalNumber, new anchor.BN(50), deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(stakeLamports))
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        goalAccount: goalPDA,
+        goalStake: goalStakePDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    const goalAccount = await program.account.goalAccount.fetch(goalPDA);
+    expect(goalAccount.stakeLamports.toNumber()).to.equal(stakeLamports);
+    expect(await provider.connection.getBalance(goalStakePDA)).to.equal(stakeLamports);
+
+    try {
+      await program.methods
+        .deleteGoal()
+        .accounts({
+          user: testUser.publicKey,
+          userAccount: userAccountPDA,
+          goalAccount: goalPDA,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([testUser])
+        .rpc();
+      expect.fail("Expected goal stake locked error");
+    } catch (error) {
+      expect(error.message).to.include("GoalStakeLocked");
+    }
+  });
+
+  it("Test vault deposits stay locked until the goal is met", async () => {
+    const goalNumber = new anchor.BN(0);
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 86400); // 1 day deadline
+    [goalPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("goal_account"), userAccountPDA.toBuffer(), goalNumber.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [goalVaultPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("goal_vault"), goalPDA.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .createGoal(goalNumber, new anchor.BN(50_000), deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        goalAccount: goalPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    await program.methods
+      .depositToVault(new anchor.BN(0.2 * LAMPORTS_PER_SOL))
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        goalAccount: goalPDA,
+        goalVault: goalVaultPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    const goalAccount = await program.account.goalAccount.fetch(goalPDA);
+    expect(goalAccount.vaultLamports.toNumber()).to.equal(0.2 * LAMPORTS_PER_SOL);
+
+    try {
+      await program.methods
+        .withdrawFromVault()
+        .accounts({
+          user: testUser.publicKey,
+          userAccount: userAccountPDA,
+          goalAccount: goalPDA,
+          goalVault: goalVaultPDA,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([testUser])
+        .rpc();
+      expect.fail("Expected vault locked error");
+    } catch (error) {
+      expect(error.message).to.include("VaultLocked");
+    }
+  });
+
+  it("Test members contribute to a shared goal", async () => {
+    const partner = Keypair.generate();
+    const outsider = Keypair.generate();
+    const goalNumber = new anchor.BN(0);
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 86400); // 1 day deadline
+    const [sharedGoalPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("shared_goal"), testUser.publicKey.toBuffer(), goalNumber.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .createSharedGoal(goalNumber, new anchor.BN(1_000), deadline, [partner.publicKey])
+      .accounts({
+        creator: testUser.publicKey,
+        sharedGoal: sharedGoalPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    await program.methods
+      .contributeSharedGoal(new anchor.BN(0.1 * LAMPORTS_PER_SOL))
+      .accounts({
+        member: testUser.publicKey,
+        sharedGoal: sharedGoalPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    const sharedGoal = await program.account.sharedGoal.fetch(sharedGoalPDA);
+    expect(sharedGoal.memberCount).to.equal(2);
+    expect(sharedGoal.members[1].toString()).to.equal(partner.publicKey.toString());
+    expect(sharedGoal.contributions[0].toNumber()).to.equal(0.1 * LAMPORTS_PER_SOL);
+
+    try {
+      await program.methods
+        .contributeSharedGoal(new anchor.BN(0.1 * LAMPORTS_PER_SOL))
+        .accounts({
+          member: outsider.publicKey,
+          sharedGoal: sharedGoalPDA,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([outsider])
+        .rpc();
+      expect.fail("Expected not a member error");
+    } catch (error) {
+      expect(error.message).to.include("NotSharedGoalMember");
+    }
+  });
+
+  it("Test guardian creates and edits a goal for a dependent", async () => {
+    const guardian = Keypair.generate();
+    const airdrop = await provider.connection.requestAirdrop(guardian.publicKey, 1 * LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(airdrop);
+
+    const [guardianLinkPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("guardian_link"), userAccountPDA.toBuffer()],
+      program.programId
+    );
+    const goalNumber = new anchor.BN(0);
+    [goalPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("goal_account"), userAccountPDA.toBuffer(), goalNumber.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 86400); // 1 day deadline
+
+    // The dependent co-signs the link once
+    await program.methods
+      .linkGuardian(true)
+      .accounts({
+        guardian: guardian.publicKey,
+        dependent: testUser.publicKey,
+        dependentAccount: userAccountPDA,
+        guardianLink: guardianLinkPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([guardian, testUser])
+      .rpc();
+
+    await program.methods
+      .guardianCreateGoal(goalNumber, { target: {} }, new anchor.BN(100), new anchor.BN(0), deadline)
+      .accounts({
+        guardian: guardian.publicKey,
+        dependentAccount: userAccountPDA,
+        guardianLink: guardianLinkPDA,
+        goalAccount: goalPDA,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([guardian])
+      .rpc();
+
+    await program.methods
+      .guardianUpdateGoal(new anchor.BN(200), new anchor.BN(0), deadline)
+      .accounts({
+        guardian: guardian.publicKey,
+        dependentAccount: userAccountPDA,
+        guardianLink: guardianLinkPDA,
+        goalAccount: goalPDA,
+      } as any)
+      .signers([guardian])
+      .rpc();
+
+    const goalAccount = await program.account.goalAccount.fetch(goalPDA);
+    expect(goalAccount.user.toString()).to.equal(testUser.publicKey.toString());
+    expect(goalAccount.guardian.toString()).to.equal(guardian.publicKey.toString());
+    expect(goalAccount.targetUsd.toNumber()).to.equal(200);
+  });
+
+  it("Test user starts an admin-defined quest", async () => {
+    const configAccount = await program.account.config.fetch(configPDA);
+    const [questPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("quest"), configPDA.toBuffer(), configAccount.questCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const goalNumber = new anchor.BN(0);
+    [goalPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("goal_account"), userAccountPDA.toBuffer(), goalNumber.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const steps = [
+      { kind: { target: {} }, targetUsd: new anchor.BN(100), targetSol: new anchor.BN(0), holdDays: 0 },
+      { kind: { target: {} }, targetUsd: new anchor.BN(500), targetSol: new anchor.BN(0), holdDays: 0 },
+      { kind: { holdDuration: {} }, targetUsd: new anchor.BN(500), targetSol: new anchor.BN(0), holdDays: 7 },
+    ];
+
+    await program.methods
+      .createQuest(steps, new anchor.BN(86400 * 30), new anchor.BN(2000))
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        questTemplate: questPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .startQuest(goalNumber)
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        questTemplate: questPDA,
+        goalAccount: goalPDA,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    const goalAccount = await program.account.goalAccount.fetch(goalPDA);
+    expect(goalAccount.quest.toString()).to.equal(questPDA.toString());
+    expect(goalAccount.questStep).to.equal(0);
+    expect(goalAccount.targetUsd.toNumber()).to.equal(100);
+  });
+
+  it("Test user creates a goal from an admin template", async () => {
+    const configAccount = await program.account.config.fetch(configPDA);
+    const [templatePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("goal_template"), configPDA.toBuffer(), configAccount.goalTemplateCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const goalNumber = new anchor.BN(0);
+    [goalPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("goal_account"), userAccountPDA.toBuffer(), goalNumber.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .createGoalTemplate({
+        name: "Emergency fund",
+        kind: { target: {} },
+        targetUsd: new anchor.BN(1_000),
+        targetSol: new anchor.BN(0),
+        growthBps: 0,
+        holdDays: 0,
+        duration: new anchor.BN(86400 * 90),
+        firstCompletedPointsOverride: new anchor.BN(5_000),
+        dailyPointsOverride: new anchor.BN(100),
+      })
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        goalTemplate: templatePDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .createGoalFromTemplate(goalNumber)
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        goalTemplate: templatePDA,
+        goalAccount: goalPDA,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    const goalAccount = await program.account.goalAccount.fetch(goalPDA);
+    expect(goalAccount.targetUsd.toNumber()).to.equal(1_000);
+    expect(goalAccount.firstCompletedPointsOverride.toNumber()).to.equal(5_000);
+    expect(goalAccount.dailyPointsOverride.toNumber()).to.equal(100);
+  });
+
+  it("Test goal slots limit open goals until a goal is deleted", async () => {
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 60 * 60 * 24 * 30); // 30 days from now
+    const goalPDAs = [0, 1].map((number) => PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("goal_account"),
+        userAccountPDA.toBuffer(),
+        new anchor.BN(number).toBuffer('le', 8)
+      ],
+      program.programId
+    )[0]);
+    const createGoal = (number: number) => program.methods
+      .createGoal(new anchor.BN(number), new anchor.BN(500), deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        goalAccount: goalPDAs[number],
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    await program.methods
+      .setGoalSlots(1)
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    await createGoal(0);
+
+    try {
+      await createGoal(1);
+      expect.fail("Expected no goal slot error");
+    } catch (error) {
+      expect(error.message).to.include("NoGoalSlot");
+    }
+
+    // Deleting a goal frees its slot
+    await program.methods
+      .deleteGoal()
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        goalAccount: goalPDAs[0],
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    await createGoal(1);
+    const userAccount = await program.account.userAccount.fetch(userAccountPDA);
+    expect(userAccount.openGoals).to.equal(1);
+    // Re-initializing keeps the goal slots, lift the limit again for the other tests
+    await program.methods
+      .setGoalSlots(0)
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+  });
+});
+
+describe("MomentumFi - Test Delete Goal", () => {
+  // Configure the client to use the local cluster.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Momentumfi as Program<Momentumfi>;
+
+  // Test accounts
+  const admin = configAdmin; // The config can only be re-initialized by its authority
+  let testUser: Keypair;
+  let anotherUser: Keypair;
+
+  // Declaring PDAs
+  let configPDA: PublicKey;
+  let rewardsMintPDA: PublicKey;
+  let userAccountPDA: PublicKey;
+  let goalPDA: PublicKey;
 
   beforeEach(async () => {
     // Generate new test users
@@ -689,7 +1587,7 @@ describe("MomentumFi - Test Delete Goal", () => {
     // Initialize the config account
     try {
       await program.methods
-        .initialize(1000, 50)
+        .initialize(1000, 50, false, false)
         .accounts({
           admin: admin.publicKey,
           configAccount: configPDA,
@@ -740,7 +1638,7 @@ describe("MomentumFi - Test Delete Goal", () => {
     // Create a goal
     try {
       await program.methods
-        .createGoal(goalNumber, targetUsd, deadline)
+        .createGoal(goalNumber, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           user: testUser.publicKey,
           userAccount: userAccountPDA,
@@ -801,7 +1699,7 @@ describe("MomentumFi - Test Delete Goal", () => {
     // Create a goal
     try {
       await program.methods
-        .createGoal(goalNumber, targetUsd, deadline)
+        .createGoal(goalNumber, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           user: testUser.publicKey,
           userAccount: userAccountPDA,
@@ -880,7 +1778,7 @@ describe("MomentumFi - Test Update Reward Points User", () => {
   const program = anchor.workspace.Momentumfi as Program<Momentumfi>;
 
   // Test accounts
-  const admin = Keypair.generate();
+  const admin = configAdmin; // The config can only be re-initialized by its authority
   let testUser: Keypair;
 
   // PDAs
@@ -948,7 +1846,7 @@ describe("MomentumFi - Test Update Reward Points User", () => {
     // Initialize the config account
     try {
       await program.methods
-        .initialize(1000, 50)
+        .initialize(1000, 50, false, false)
         .accounts({
           admin: admin.publicKey,
           configAccount: configPDA,
@@ -989,7 +1887,7 @@ describe("MomentumFi - Test Update Reward Points User", () => {
 
     // Create a goal
     await program.methods
-      .createGoal(goalNumber, targetUsd, deadline)
+      .createGoal(goalNumber, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
       .accounts({
         user: testUser.publicKey,
         userAccount: userAccountPDA,
@@ -1044,7 +1942,7 @@ describe("MomentumFi - Test Update Reward Points User", () => {
 
     // Create a goal
     await program.methods
-      .createGoal(goalNumber, targetUsd, deadline)
+      .createGoal(goalNumber, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
       .accounts({
         user: testUser.publicKey,
         userAccount: userAccountPDA,
@@ -1056,7 +1954,7 @@ describe("MomentumFi - Test Update Reward Points User", () => {
     
     // Create second goal
     await program.methods
-    .createGoal(goal2Number, targetUsd, deadline)
+    .createGoal(goal2Number, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
     .accounts({
       user: testUser.publicKey,
       userAccount: userAccountPDA,
@@ -1068,7 +1966,7 @@ describe("MomentumFi - Test Update Reward Points User", () => {
 
     // Create third goal
     await program.methods
-      .createGoal(goal3Number, unreachedTargetUsd, deadline)
+      .createGoal(goal3Number, unreachedTargetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
       .accounts({
         user: testUser.publicKey,
         userAccount: userAccountPDA,
@@ -1147,7 +2045,7 @@ describe("MomentumFi - Test Update Reward Points User", () => {
   
     // Create a goal with an unreachable target
     await program.methods
-      .createGoal(goalNumber, targetUsd, deadline)
+      .createGoal(goalNumber, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
       .accounts({
         user: testUser.publicKey,
         userAccount: userAccountPDA,
@@ -1188,6 +2086,184 @@ describe("MomentumFi - Test Update Reward Points User", () => {
     expect(goalAccountAfter.completed).to.be.false;
     expect(goalAccountAfter.firstCompletedBonus).to.be.false;
   });
+
+  it("Test records a breach when the balance drops below a floor goal", async () => {
+    const floorUsd = new anchor.BN(5_000); // Above the current balance
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 86400);
+    let goalNumber = new anchor.BN(0);
+
+    await program.methods
+      .createGoal(goalNumber, floorUsd, deadline, { stayAbove: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        goalAccount: goalPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    await program.methods
+      .updateRewardPointsUser()
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        config: configPDA,
+        priceUpdate: priceFeedPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts([{ pubkey: goalPDA, isWritable: true, isSigner: false }])
+      .signers([testUser])
+      .rpc();
+
+    const userAccountAfter = await program.account.userAccount.fetch(userAccountPDA);
+    const goalAccountAfter = await program.account.goalAccount.fetch(goalPDA);
+
+    expect(goalAccountAfter.breached).to.be.true;
+    expect(goalAccountAfter.breachTimestamp.toNumber()).to.be.greaterThan(0);
+    expect(goalAccountAfter.breachUsdBalance.eq(userAccountAfter.usdBalance)).to.be.true;
+    expect(goalAccountAfter.completed).to.be.false;
+    expect(userAccountAfter.claimableRewards.toNumber()).to.be.equal(0);
+  });
+
+  it("Test hold goal starts a streak without paying before the hold ends", async () => {
+    const targetUsd = new anchor.BN(50);
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 86400 * 30);
+    let goalNumber = new anchor.BN(0);
+
+    await program.methods
+      .createGoal(goalNumber, targetUsd, deadline, { holdDuration: {} }, 0, 7, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        goalAccount: goalPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    await program.methods
+      .updateRewardPointsUser()
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        config: configPDA,
+        priceUpdate: priceFeedPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts([{ pubkey: goalPDA, isWritable: true, isSigner: false }])
+      .signers([testUser])
+      .rpc();
+
+    const userAccountAfter = await program.account.userAccount.fetch(userAccountPDA);
+    const goalAccountAfter = await program.account.goalAccount.fetch(goalPDA);
+
+    expect(goalAccountAfter.holdDays).to.equal(7);
+    expect(goalAccountAfter.streakStartTimestamp.toNumber()).to.be.greaterThan(0);
+    expect(goalAccountAfter.streakDays).to.equal(0);
+    expect(goalAccountAfter.lastHoldObservation.eq(goalAccountAfter.streakStartTimestamp)).to.be.true;
+    expect(goalAccountAfter.completed).to.be.false;
+    expect(userAccountAfter.claimableRewards.toNumber()).to.be.equal(0);
+  });
+
+  it("Test reward update ranks the user on the leaderboard", async () => {
+    const [leaderboardPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("leaderboard"), configPDA.toBuffer()],
+      program.programId
+    );
+    const targetUsd = new anchor.BN(50);
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 86400); // 1 day deadline
+
+    await program.methods
+      .createGoal(new anchor.BN(0), targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        goalAccount: goalPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    await program.methods
+      .updateRewardPointsUser()
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        config: configPDA,
+        priceUpdate: priceFeedPDA,
+        leaderboard: leaderboardPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts([
+        { pubkey: goalPDA, isWritable: true, isSigner: false }
+      ])
+      .signers([testUser])
+      .rpc();
+
+    const userAccountAfter = await program.account.userAccount.fetch(userAccountPDA);
+    const leaderboard = await program.account.leaderboard.fetch(leaderboardPDA);
+    const entry = leaderboard.entries.find(e => e.user.equals(testUser.publicKey));
+    expect(entry).to.not.be.undefined;
+    expect(entry.points.toNumber()).to.equal(userAccountAfter.totalPoints.toNumber());
+  });
+
+  it("Test fails if another user passes someone else's goal", async () => {
+    const otherUser = Keypair.generate();
+    const airdrop = await provider.connection.requestAirdrop(otherUser.publicKey, 5 * LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(airdrop);
+    const [otherUserAccountPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("user_account"), otherUser.publicKey.toBuffer()],
+      program.programId
+    );
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 86400); // 1 day deadline
+
+    await program.methods
+      .registerUserAccount()
+      .accounts({
+        user: otherUser.publicKey,
+        userAccount: otherUserAccountPDA,
+        configAccount: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([otherUser])
+      .rpc();
+
+    await program.methods
+      .createGoal(new anchor.BN(0), new anchor.BN(500), deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        goalAccount: goalPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    // The richer wallet must not be able to complete the goal and unlock its vault
+    try {
+      await program.methods
+        .updateRewardPointsUser()
+        .accounts({
+          user: otherUser.publicKey,
+          userAccount: otherUserAccountPDA,
+          config: configPDA,
+          priceUpdate: priceFeedPDA,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .remainingAccounts([
+          { pubkey: goalPDA, isWritable: true, isSigner: false }
+        ])
+        .signers([otherUser])
+        .rpc();
+      expect.fail("Expected invalid goal account error");
+    } catch (error) {
+      expect(error.message).to.include("InvalidGoalAccount");
+    }
+
+    const goalAccount = await program.account.goalAccount.fetch(goalPDA);
+    expect(goalAccount.completed).to.be.false;
+  });
 });
 
 
@@ -1199,7 +2275,7 @@ describe("MomentumFi - Test Update Reward Points Admin", () => {
   const program = anchor.workspace.Momentumfi as Program<Momentumfi>;
 
   // Test accounts
-  const admin = Keypair.generate();
+  const admin = configAdmin; // The config can only be re-initialized by its authority
   let testUser: Keypair;
 
   // PDAs
@@ -1267,7 +2343,7 @@ describe("MomentumFi - Test Update Reward Points Admin", () => {
     // Initialize the config account
     try {
       await program.methods
-        .initialize(1000, 50)
+        .initialize(1000, 50, false, false)
         .accounts({
           admin: admin.publicKey,
           configAccount: configPDA,
@@ -1307,7 +2383,7 @@ describe("MomentumFi - Test Update Reward Points Admin", () => {
 
     // Create a goal
     await program.methods
-      .createGoal(goalNumber, targetUsd, deadline)
+      .createGoal(goalNumber, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
       .accounts({
         user: testUser.publicKey,
         userAccount: userAccountPDA,
@@ -1359,7 +2435,7 @@ describe("MomentumFi - Test Update Reward Points Admin", () => {
 
     // Create a goal
     await program.methods
-      .createGoal(goalNumber, targetUsd, deadline)
+      .createGoal(goalNumber, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
       .accounts({
         user: testUser.publicKey,
         userAccount: userAccountPDA,
@@ -1392,7 +2468,69 @@ describe("MomentumFi - Test Update Reward Points Admin", () => {
       .rpc();
       expect.fail("Expected unauthorized access error");    
     } catch (error) {
-      expect(error.message).to.include("UnauthorizedAccess");
+      expect(error.message).to.include("UnauthorizedAccess");
+    }
+  });
+
+  it("Test admin grants streak freezes to a user", async () => {
+    await program.methods
+      .grantStreakFreezes(3)
+      .accounts({
+        admin: admin.publicKey,
+        userAccount: userAccountPDA,
+        config: configPDA,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    const userAccountAfter = await program.account.userAccount.fetch(userAccountPDA);
+    expect(userAccountAfter.streakFreezes).to.equal(3);
+  });
+
+  it("Test fails to mint an achievement before the milestone is reached", async () => {
+    const tokenMetadataProgram = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+    const milestoneSeed = Buffer.from([0]);
+
+    const [achievementRecordPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("achievement"), userAccountPDA.toBuffer(), milestoneSeed],
+      program.programId
+    );
+    const [achievementMintPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("achievement_mint"), userAccountPDA.toBuffer(), milestoneSeed],
+      program.programId
+    );
+    const [metadataPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), tokenMetadataProgram.toBuffer(), achievementMintPDA.toBuffer()],
+      tokenMetadataProgram
+    );
+    const [masterEditionPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), tokenMetadataProgram.toBuffer(), achievementMintPDA.toBuffer(), Buffer.from("edition")],
+      tokenMetadataProgram
+    );
+    const userAchievementAta = await getAssociatedTokenAddress(achievementMintPDA, testUser.publicKey);
+
+    try {
+      await program.methods
+        .mintAchievement({ firstGoal: {} })
+        .accounts({
+          user: testUser.publicKey,
+          userAccount: userAccountPDA,
+          achievementRecord: achievementRecordPDA,
+          achievementMint: achievementMintPDA,
+          userAchievementAta,
+          metadata: metadataPDA,
+          masterEdition: masterEditionPDA,
+          config: configPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenMetadataProgram,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([testUser])
+        .rpc();
+      expect.fail("Expected milestone not reached error");
+    } catch (error) {
+      expect(error.message).to.include("MilestoneNotReached");
     }
   });
 });
@@ -1407,7 +2545,7 @@ describe("MomentumFi - Test Claim Rewards", () => {
   const program = anchor.workspace.Momentumfi as Program<Momentumfi>;
 
   // Test accounts
-  const admin = Keypair.generate();
+  const admin = configAdmin; // The config can only be re-initialized by its authority
   let testUser: Keypair;
 
   // PDAs
@@ -1485,7 +2623,7 @@ describe("MomentumFi - Test Claim Rewards", () => {
     // Initialize the config account
     try {
       await program.methods
-        .initialize(1000, 50)
+        .initialize(1000, 50, false, false)
         .accounts({
           admin: admin.publicKey,
           configAccount: configPDA,
@@ -1525,7 +2663,7 @@ describe("MomentumFi - Test Claim Rewards", () => {
 
     // Create a goal
     await program.methods
-      .createGoal(goalNumber, targetUsd, deadline)
+      .createGoal(goalNumber, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
       .accounts({
         user: testUser.publicKey,
         userAccount: userAccountPDA,
@@ -1571,10 +2709,16 @@ describe("MomentumFi - Test Claim Rewards", () => {
       .signers([testUser])
       .rpc();
 
-    // Check updated balance (should now be 1000)
+    // Check updated balance (should now be 1000 tokens, 1 token per point)
     ataAccount = await provider.connection.getTokenAccountBalance(userRewardsAta);
-    expect(Number(ataAccount.value.amount)).to.equal(1000);
+    expect(ataAccount.value.uiAmount).to.equal(1000);
 
+    // Lifetime totals are kept after claiming
+    const userAccountAfter = await program.account.userAccount.fetch(userAccountPDA);
+    const goalAccountAfter = await program.account.goalAccount.fetch(goalPDA);
+    expect(userAccountAfter.claimableRewards.toNumber()).to.equal(0);
+    expect(userAccountAfter.totalPoints.toNumber()).to.equal(1000);
+    expect(goalAccountAfter.totalPoints.toNumber()).to.equal(1000);
   });
 
   it("Test user successfully claims rewards multiple times", async () => {
@@ -1587,7 +2731,7 @@ describe("MomentumFi - Test Claim Rewards", () => {
 
     // Create a goal
     await program.methods
-      .createGoal(goalNumber, targetUsd, deadline)
+      .createGoal(goalNumber, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
       .accounts({
         user: testUser.publicKey,
         userAccount: userAccountPDA,
@@ -1599,7 +2743,7 @@ describe("MomentumFi - Test Claim Rewards", () => {
     
     // Create second goal
     await program.methods
-    .createGoal(goal2Number, targetUsd, deadline)
+    .createGoal(goal2Number, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
     .accounts({
       user: testUser.publicKey,
       userAccount: userAccountPDA,
@@ -1611,7 +2755,7 @@ describe("MomentumFi - Test Claim Rewards", () => {
 
     // Create third goal
     await program.methods
-      .createGoal(goal3Number, unreachedTargetUsd, deadline)
+      .createGoal(goal3Number, unreachedTargetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
       .accounts({
         user: testUser.publicKey,
         userAccount: userAccountPDA,
@@ -1655,7 +2799,7 @@ describe("MomentumFi - Test Claim Rewards", () => {
 
     // Check updated balance (should now be 1000)
     let ataAccount = await provider.connection.getTokenAccountBalance(userRewardsAta);
-    expect(Number(ataAccount.value.amount)).to.equal(1000);
+    expect(ataAccount.value.uiAmount).to.equal(1000);
 
     // Call update_reward_points_user
     await program.methods
@@ -1692,7 +2836,7 @@ describe("MomentumFi - Test Claim Rewards", () => {
 
     // Check updated balance (should now be 1000)
     ataAccount = await provider.connection.getTokenAccountBalance(userRewardsAta);
-    expect(Number(ataAccount.value.amount)).to.equal(2000);
+    expect(ataAccount.value.uiAmount).to.equal(2000);
   });
 
   it("Test fails to claim rewards if user has no claimable rewards", async () => {
@@ -1702,7 +2846,7 @@ describe("MomentumFi - Test Claim Rewards", () => {
 
     // Create a goal
     await program.methods
-      .createGoal(goalNumber, unreachedTargetUsd, deadline)
+      .createGoal(goalNumber, unreachedTargetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
       .accounts({
         user: testUser.publicKey,
         userAccount: userAccountPDA,
@@ -1749,5 +2893,292 @@ describe("MomentumFi - Test Claim Rewards", () => {
       expect(err.message).to.include("NoPointsToClaim");
     }
   });
+
+  it("Test claim mints into the vesting escrow when vesting is enabled", async () => {
+    const targetUsd = new anchor.BN(50);
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 86400);
+    let goalNumber = new anchor.BN(0);
+
+    const [vestingPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vesting"), testUser.publicKey.toBuffer()],
+      program.programId
+    );
+    const vestingEscrow = await getAssociatedTokenAddress(rewardsMintPDA, vestingPDA, true);
+
+    // Enable vesting with a one hour cliff and a 30 day linear unlock
+    await program.methods
+      .setVesting(true, new anchor.BN(3600), new anchor.BN(86400 * 30))
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .createGoal(goalNumber, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        goalAccount: goalPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    await program.methods
+      .updateRewardPointsUser()
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        config: configPDA,
+        priceUpdate: priceFeedPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts([
+        { pubkey: goalPDA, isWritable: true, isSigner: false }
+      ])
+      .signers([testUser])
+      .rpc();
+
+    await program.methods
+      .claimRewards()
+      .accounts({
+        user: testUser.publicKey,
+        userRewardsAta,
+        userAccount: userAccountPDA,
+        config: configPDA,
+        rewardsMint: rewardsMintPDA,
+        vestingAccount: vestingPDA,
+        vestingEscrow,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    // Everything is locked in the escrow until the cliff
+    const escrowBalance = await provider.connection.getTokenAccountBalance(vestingEscrow);
+    const userBalance = await provider.connection.getTokenAccountBalance(userRewardsAta);
+    expect(escrowBalance.value.uiAmount).to.equal(1000);
+    expect(userBalance.value.uiAmount).to.equal(0);
+
+    const vestingAccount = await program.account.vestingAccount.fetch(vestingPDA);
+    expect(vestingAccount.scheduleAmount.eq(new anchor.BN(1000 * 10 ** 6))).to.be.true;
+
+    try {
+      await program.methods
+        .withdrawVested()
+        .accounts({
+          user: testUser.publicKey,
+          userRewardsAta,
+          vestingAccount: vestingPDA,
+          vestingEscrow,
+          config: configPDA,
+          rewardsMint: rewardsMintPDA,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([testUser])
+        .rpc();
+
+      expect.fail("Expected withdraw to fail before the cliff, but it succeeded.");
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("NothingToWithdraw");
+    }
+    // Re-initializing keeps the vesting settings, turn vesting off again for the other tests
+    await program.methods
+      .setVesting(false, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+  });
+
+  it("Test user stakes and unstakes claimed reward tokens", async () => {
+    const targetUsd = new anchor.BN(50);
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 86400);
+    let goalNumber = new anchor.BN(0);
+
+    const [stakePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stake"), testUser.publicKey.toBuffer()],
+      program.programId
+    );
+    const [stakeVaultPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_vault"), configPDA.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .createGoal(goalNumber, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        goalAccount: goalPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    await program.methods
+      .updateRewardPointsUser()
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        config: configPDA,
+        priceUpdate: priceFeedPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts([
+        { pubkey: goalPDA, isWritable: true, isSigner: false }
+      ])
+      .signers([testUser])
+      .rpc();
+
+    await program.methods
+      .claimRewards()
+      .accounts({
+        user: testUser.publicKey,
+        userRewardsAta,
+        userAccount: userAccountPDA,
+        config: configPDA,
+        rewardsMint: rewardsMintPDA,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    // Stake half of the claimed tokens without a lock
+    const stakeAmount = new anchor.BN(500 * 10 ** 6);
+    await program.methods
+      .stakeRewards(stakeAmount, new anchor.BN(0))
+      .accounts({
+        user: testUser.publicKey,
+        userRewardsAta,
+        stakeAccount: stakePDA,
+        stakeVault: stakeVaultPDA,
+        config: configPDA,
+        rewardsMint: rewardsMintPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    const stakeAccount = await program.account.stakeAccount.fetch(stakePDA);
+    expect(stakeAccount.amount.eq(stakeAmount)).to.be.true;
+    let userBalance = await provider.connection.getTokenAccountBalance(userRewardsAta);
+    expect(userBalance.value.uiAmount).to.equal(500);
+
+    await program.methods
+      .unstakeRewards()
+      .accounts({
+        user: testUser.publicKey,
+        userRewardsAta,
+        stakeAccount: stakePDA,
+        stakeVault: stakeVaultPDA,
+        config: configPDA,
+        rewardsMint: rewardsMintPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    userBalance = await provider.connection.getTokenAccountBalance(userRewardsAta);
+    expect(userBalance.value.uiAmount).to.equal(1000);
+    expect(await provider.connection.getAccountInfo(stakePDA)).to.be.null;
+  });
+
+  it("Test user burns reward tokens to redeem a perk", async () => {
+    const targetUsd = new anchor.BN(50);
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 86400);
+    let goalNumber = new anchor.BN(0);
+
+    const [perkCatalogPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("perk_catalog"), configPDA.toBuffer()],
+      program.programId
+    );
+
+    // Streak freezes cost 100 tokens
+    await program.methods
+      .setPerk({ streakFreeze: {} }, new anchor.BN(100 * 10 ** 6), true)
+      .accounts({
+        admin: admin.publicKey,
+        config: configPDA,
+        perkCatalog: perkCatalogPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .createGoal(goalNumber, targetUsd, deadline, { target: {} }, 0, 0, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        goalAccount: goalPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    await program.methods
+      .updateRewardPointsUser()
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        config: configPDA,
+        priceUpdate: priceFeedPDA,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts([
+        { pubkey: goalPDA, isWritable: true, isSigner: false }
+      ])
+      .signers([testUser])
+      .rpc();
+
+    await program.methods
+      .claimRewards()
+      .accounts({
+        user: testUser.publicKey,
+        userRewardsAta,
+        userAccount: userAccountPDA,
+        config: configPDA,
+        rewardsMint: rewardsMintPDA,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    await program.methods
+      .redeemPerk({ streakFreeze: {} })
+      .accounts({
+        user: testUser.publicKey,
+        userAccount: userAccountPDA,
+        userRewardsAta,
+        perkCatalog: perkCatalogPDA,
+        config: configPDA,
+        rewardsMint: rewardsMintPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      } as any)
+      .signers([testUser])
+      .rpc();
+
+    const userBalance = await provider.connection.getTokenAccountBalance(userRewardsAta);
+    expect(userBalance.value.uiAmount).to.equal(900);
+    const userAccountAfter = await program.account.userAccount.fetch(userAccountPDA);
+    expect(userAccountAfter.streakFreezes).to.equal(1);
+  });
 });
 